@@ -0,0 +1,84 @@
+use alloc::vec::Vec;
+use core::fmt;
+
+/// Error from decoding base64 data.
+///
+/// This is returned by [`Args::option_value_bytes`](crate::Args::option_value_bytes)
+/// method when option's value is not valid base64 data.
+///
+/// This enum is available only with the crate feature `base64`.
+
+#[derive(Debug, PartialEq)]
+pub enum Base64DecodeError {
+    /// Invalid character in the data. The values are the byte offset
+    /// and the byte.
+    InvalidByte(usize, u8),
+    /// The length of the data is not valid.
+    InvalidLength,
+}
+
+impl fmt::Display for Base64DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidByte(i, b) => {
+                write!(f, "Invalid byte {} at offset {} in base64 data.", b, i)
+            }
+            Self::InvalidLength => write!(f, "Invalid length of base64 data."),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Base64DecodeError {}
+
+pub fn decode(s: &str) -> Result<Vec<u8>, Base64DecodeError> {
+    let data = s.trim_end_matches('=');
+    let padding = s.len() - data.len();
+
+    if padding > 2 || (padding > 0 && s.len() % 4 != 0) || data.len() % 4 == 1 {
+        return Err(Base64DecodeError::InvalidLength);
+    }
+
+    let mut result = Vec::with_capacity(data.len() * 3 / 4);
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+
+    for (i, b) in data.bytes().enumerate() {
+        let value = match b {
+            b'A'..=b'Z' => b - b'A',
+            b'a'..=b'z' => b - b'a' + 26,
+            b'0'..=b'9' => b - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            _ => return Err(Base64DecodeError::InvalidByte(i, b)),
+        };
+
+        acc = (acc << 6) | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            result.push((acc >> bits) as u8);
+            acc &= (1 << bits) - 1;
+        }
+    }
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn t_decode() {
+        assert_eq!(Ok(b"".to_vec()), decode(""));
+        assert_eq!(Ok(b"f".to_vec()), decode("Zg=="));
+        assert_eq!(Ok(b"fo".to_vec()), decode("Zm8="));
+        assert_eq!(Ok(b"foo".to_vec()), decode("Zm9v"));
+        assert_eq!(Ok(b"foob".to_vec()), decode("Zm9vYg"));
+        assert_eq!(Ok(b"\xfb\xff".to_vec()), decode("+/8="));
+        assert_eq!(Err(Base64DecodeError::InvalidLength), decode("Zm9vY"));
+        assert_eq!(Err(Base64DecodeError::InvalidLength), decode("Zg="));
+        assert_eq!(Err(Base64DecodeError::InvalidLength), decode("Zg==="));
+        assert_eq!(Err(Base64DecodeError::InvalidByte(2, b'-')), decode("Zm-v"));
+    }
+}
@@ -0,0 +1,162 @@
+use crate::{prefixed_name, OptSpecs, OptValue};
+use alloc::{format, string::String};
+
+/// Generate shell completion scripts from option specifications.
+///
+/// This trait is implemented for [`OptSpecs`] struct. The generated
+/// scripts complete the short and long option names that have been
+/// defined with [`OptSpecs::option`] and other methods. Long options
+/// can be completed from any prefix, so shortened long options (see
+/// [`OptFlags::PrefixMatchLongOptions`](crate::OptFlags::PrefixMatchLongOptions))
+/// are completed as well. Options without prefix (see
+/// [`OptSpecs::option_no_prefix`]) are not included.
+///
+/// This trait is available only with the crate feature
+/// `shell-completion`.
+pub trait ShellCompletion {
+    /// Generate a completion script for the Bash shell.
+    ///
+    /// The `program_name` argument is the name of the program as it is
+    /// written in the command line. The return value is a script which
+    /// can be loaded with Bash's `source` command.
+    fn bash_completion(&self, program_name: &str) -> String;
+
+    /// Generate a completion script for the Zsh shell.
+    ///
+    /// The `program_name` argument is the name of the program as it is
+    /// written in the command line. The return value is a script which
+    /// can be saved as file `_PROGRAM` in a directory which is listed
+    /// in Zsh's `fpath` variable.
+    fn zsh_completion(&self, program_name: &str) -> String;
+}
+
+impl ShellCompletion for OptSpecs {
+    fn bash_completion(&self, program_name: &str) -> String {
+        let mut words = String::new();
+
+        for spec in self.options.iter().filter(|s| !s.no_prefix) {
+            if !words.is_empty() {
+                words.push(' ');
+            }
+            words.push_str(&bash_quote(&prefixed_name(&spec.name)));
+        }
+
+        format!(
+            "_{func}() {{\n    \
+             local cur=\"${{COMP_WORDS[COMP_CWORD]}}\"\n    \
+             COMPREPLY=($(compgen -W \"{words}\" -- \"$cur\"))\n\
+             }}\n\
+             complete -F _{func} {prog}\n",
+            func = function_name(program_name),
+            words = words,
+            prog = program_name,
+        )
+    }
+
+    fn zsh_completion(&self, program_name: &str) -> String {
+        let mut script = format!("#compdef {}\n\n_arguments", program_name);
+
+        for spec in self.options.iter().filter(|s| !s.no_prefix) {
+            let long = spec.name.chars().count() > 1;
+            let suffix = match (&spec.value_type, long) {
+                (OptValue::None, _) => "",
+                (OptValue::Required | OptValue::RequiredNonEmpty, false) => "+:value:",
+                (OptValue::Required | OptValue::RequiredNonEmpty, true) => "=:value:",
+                (_, false) => "-::value:",
+                (_, true) => "=-::value:",
+            };
+            script.push_str(" \\\n    '");
+            script.push_str(&zsh_quote(&prefixed_name(&spec.name)));
+            script.push_str(suffix);
+            script.push('\'');
+        }
+
+        script.push('\n');
+        script
+    }
+}
+
+fn function_name(program_name: &str) -> String {
+    program_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+fn bash_quote(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        if "\"$`\\".contains(c) {
+            result.push('\\');
+        }
+        result.push(c);
+    }
+    result
+}
+
+fn zsh_quote(s: &str) -> String {
+    let mut result = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '\'' => result.push_str("'\\''"),
+            ':' | '[' | ']' | '\\' => {
+                result.push('\\');
+                result.push(c);
+            }
+            _ => result.push(c),
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn t_bash_completion() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None)
+            .option("file", "file", OptValue::Required)
+            .option_no_prefix("build", "build", OptValue::None);
+
+        assert_eq!(
+            "_my_prog() {\n    \
+             local cur=\"${COMP_WORDS[COMP_CWORD]}\"\n    \
+             COMPREPLY=($(compgen -W \"-h --help --file\" -- \"$cur\"))\n\
+             }\n\
+             complete -F _my_prog my-prog\n",
+            specs.bash_completion("my-prog")
+        );
+    }
+
+    #[test]
+    fn t_zsh_completion() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::RequiredNonEmpty)
+            .option("verbose", "v", OptValue::Optional)
+            .option("verbose", "verbose", OptValue::OptionalNonEmpty)
+            .option("colon", "a:b", OptValue::None);
+
+        assert_eq!(
+            "#compdef prog\n\n_arguments \\\n    \
+             '-h' \\\n    \
+             '-f+:value:' \\\n    \
+             '--file=:value:' \\\n    \
+             '-v-::value:' \\\n    \
+             '--verbose=-::value:' \\\n    \
+             '--a\\:b'\n",
+            specs.zsh_completion("prog")
+        );
+    }
+
+    #[test]
+    fn t_quote() {
+        assert_eq!("a\\$b\\\"", bash_quote("a$b\""));
+        assert_eq!("a'\\''b\\[\\]", zsh_quote("a'b[]"));
+        assert_eq!("my_prog_1", function_name("my-prog.1"));
+    }
+}
@@ -0,0 +1,116 @@
+use crate::OptValue;
+use alloc::{string::String, vec::Vec};
+
+pub fn parse_help_line(line: &str) -> Vec<(String, String, OptValue)> {
+    let line = line.trim();
+    if !line.starts_with('-') {
+        return Vec::new();
+    }
+
+    let options = match line.find("  ") {
+        Some(i) => &line[..i],
+        None => line,
+    };
+
+    let mut names: Vec<String> = Vec::new();
+    let mut required = false;
+    let mut optional = false;
+
+    for word in options.split(|c: char| c == ',' || c.is_whitespace()) {
+        if word.is_empty() {
+            continue;
+        }
+
+        let name = if let Some(long) = word.strip_prefix("--") {
+            long
+        } else if let Some(short) = word.strip_prefix('-') {
+            short
+        } else {
+            if word.starts_with('[') {
+                optional = true;
+            } else {
+                required = true;
+            }
+            continue;
+        };
+
+        let (name, placeholder) = match name.find(|c| c == '=' || c == '[') {
+            Some(i) => (&name[..i], &name[i..]),
+            None => (name, ""),
+        };
+        if name.is_empty() {
+            continue;
+        }
+        if placeholder.starts_with('[') {
+            optional = true;
+        } else if !placeholder.is_empty() {
+            required = true;
+        }
+        names.push(String::from(name));
+    }
+
+    let id = names
+        .iter()
+        .find(|n| n.chars().count() > 1)
+        .or_else(|| names.first())
+        .cloned()
+        .unwrap_or_default();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let value_type = if optional {
+                OptValue::Optional
+            } else if required {
+                OptValue::Required
+            } else {
+                OptValue::None
+            };
+            (id.clone(), name, value_type)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    fn spec(id: &str, name: &str, value_type: OptValue) -> (String, String, OptValue) {
+        (String::from(id), String::from(name), value_type)
+    }
+
+    #[test]
+    fn t_parse_help_line() {
+        assert_eq!(
+            vec![
+                spec("file", "f", OptValue::Required),
+                spec("file", "file", OptValue::Required),
+            ],
+            parse_help_line("  -f, --file FILE  file to process")
+        );
+        assert_eq!(
+            vec![
+                spec("verbose", "v", OptValue::None),
+                spec("verbose", "verbose", OptValue::None),
+            ],
+            parse_help_line("-v, --verbose  enable verbosity")
+        );
+        assert_eq!(
+            vec![spec("color", "color", OptValue::Optional)],
+            parse_help_line("--color[=WHEN]  use colors")
+        );
+        assert_eq!(
+            vec![spec("o", "o", OptValue::Required)],
+            parse_help_line("-o=FILE")
+        );
+        assert_eq!(
+            vec![spec("d", "d", OptValue::Optional)],
+            parse_help_line("-d [LEVEL]   debug level")
+        );
+        assert_eq!(0, parse_help_line("Usage: prog [options]").len());
+        assert_eq!(0, parse_help_line("").len());
+        assert_eq!(0, parse_help_line("--   end of options").len());
+        assert_eq!(0, parse_help_line("- item").len());
+    }
+}
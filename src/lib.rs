@@ -39,6 +39,13 @@
 //! the option parser. Then the rest of the command line is parsed as
 //! regular arguments (non-options).
 //!
+//! A single `-` character is not an option. It is parsed as a regular
+//! argument (non-option). Programs often use it to mean standard input
+//! or output.
+//!
+//! Options can also be defined without prefix, so that they look like
+//! regular arguments. See [`OptSpecs::option_no_prefix`] method.
+//!
 //! ## Short Options
 //!
 //! Short options in the command line start with the `-` character which
@@ -76,6 +83,14 @@
 //! accepts an optional value. It means that the value is empty string.
 //! It is not valid format when the option does not accept a value.
 //!
+//! Only the first `=` character separates option's name and value. The
+//! rest of the argument is the value, including possible other `=`
+//! characters. For example, `--foo=bar=baz` gives value `bar=baz` and
+//! `--foo===baz` gives value `==baz`.
+//!
+//! The `=` separator character can be changed with
+//! [`OptSpecs::long_value_separator`] method.
+//!
 //! # Examples
 //!
 //! Following examples will guide through a typical use of this library
@@ -195,6 +210,8 @@
 //!             value: Some(
 //!                 "123",
 //!             ),
+//!             source_index: 0,
+//!             no_prefix: false,
 //!         },
 //!         Opt {
 //!             id: "file",
@@ -203,12 +220,16 @@
 //!             value: Some(
 //!                 "456",
 //!             ),
+//!             source_index: 1,
+//!             no_prefix: false,
 //!         },
 //!         Opt {
 //!             id: "verbose",
 //!             name: "v",
 //!             value_required: false,
 //!             value: None,
+//!             source_index: 3,
+//!             no_prefix: false,
 //!         },
 //!     ],
 //!     other: [
@@ -332,6 +353,25 @@
 //! }
 //! ```
 //!
+//! # Cargo Features
+//!
+//! This crate is `no_std` by default and it only needs the `alloc`
+//! crate. Optional features enable more functionality:
+//!
+//!   - `std`: Methods which need the standard library, like
+//!     `OptSpecs::parse_args_or_exit`. Documentation of such methods
+//!     mentions the feature.
+//!
+//!   - `shell-completion`: Trait `ShellCompletion` for generating shell
+//!     completion scripts from [`OptSpecs`] specification.
+//!
+//!   - `base64`: Method `Args::option_value_bytes` for decoding binary
+//!     data from base64-encoded option values. The feature uses a small
+//!     built-in decoder and does not add dependencies.
+//!
+//!   - `toml`: Method `Args::to_structured_toml` for writing the parsed
+//!     options as TOML data.
+//!
 //! # More Help
 //!
 //! A complete working example code -- very similar to previous examples
@@ -347,13 +387,31 @@
 #![warn(missing_docs)]
 #![cfg_attr(not(doc), no_std)]
 
+mod help;
 mod parser;
+mod value;
+
+pub use value::{ParseByteSizeError, ParseDurationError, ValueErrorKind};
+
+#[cfg(feature = "shell-completion")]
+mod completion;
+#[cfg(feature = "shell-completion")]
+pub use completion::ShellCompletion;
+
+#[cfg(feature = "base64")]
+mod base64;
+#[cfg(feature = "base64")]
+pub use base64::Base64DecodeError;
 
 extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 use alloc::{
+    collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
+use core::{fmt, num::ParseIntError, str::FromStr};
 
 /// Specification for program's valid command-line options.
 ///
@@ -365,10 +423,17 @@ use alloc::{
 /// The struct instance is used when parsing the command line given by
 /// program's user. The parser methods is [`getopt`](OptSpecs::getopt).
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct OptSpecs {
     options: Vec<OptSpec>,
     flags: Vec<OptFlags>,
+    dependencies: Vec<(String, String)>,
+    name_dependencies: Vec<(String, String)>,
+    exclusive_groups: Vec<Vec<String>>,
+    version: Option<String>,
+    pre_parsed: Vec<Opt>,
+    defaults: Vec<(String, String)>,
+    long_value_separator: char,
     option_limit: u32,
     other_limit: u32,
     unknown_limit: u32,
@@ -376,11 +441,48 @@ pub struct OptSpecs {
 
 const COUNTER_LIMIT: u32 = u32::MAX;
 
-#[derive(Debug, PartialEq)]
-struct OptSpec {
-    id: String,
-    name: String,
-    value_type: OptValue,
+fn prefixed_name(name: &str) -> String {
+    match name.chars().count() {
+        1 => alloc::format!("-{}", name),
+        _ => alloc::format!("--{}", name),
+    }
+}
+
+/// Specification of a single option.
+///
+/// Instances of this struct are created by [`OptSpecs::option`] and
+/// other methods, and they can be inspected with
+/// [`OptSpecs::iter_specs`] method. This is useful for generating help
+/// messages or other documentation from the same specifications that
+/// are used for parsing.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct OptSpec {
+    /// Programmer's identifier for the option. See
+    /// [`OptSpecs::option`] method.
+    pub id: String,
+
+    /// Option's name in the command line without prefix. Single
+    /// character means a short option and more characters mean a long
+    /// option.
+    pub name: String,
+
+    /// Option's value type. See enum [`OptValue`].
+    pub value_type: OptValue,
+
+    range: Option<(i64, i64)>,
+    category: Vec<String>,
+    no_prefix: bool,
+}
+
+impl OptSpec {
+    fn display_name(&self) -> String {
+        if self.no_prefix {
+            self.name.clone()
+        } else {
+            prefixed_name(&self.name)
+        }
+    }
 }
 
 /// Option's value type.
@@ -388,7 +490,7 @@ struct OptSpec {
 /// Usually used with [`OptSpecs::option`] method. Variants of this enum
 /// define if and how an option accepts a value.
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum OptValue {
     /// Option does not accept a value.
@@ -411,7 +513,7 @@ pub enum OptValue {
 /// are general configuration flags that change command-line parser's
 /// behavior.
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 #[non_exhaustive]
 pub enum OptFlags {
     /// Accept command-line options and other arguments in mixed order
@@ -430,6 +532,101 @@ pub enum OptFlags {
     /// one match the option given in the command line is classified as
     /// unknown.
     PrefixMatchLongOptions,
+
+    /// Don't parse long options at all. Command-line arguments that
+    /// look like long options (`--foo`) are parsed as other arguments
+    /// (non-options). The option terminator `--` still stops option
+    /// parsing.
+    ///
+    /// This is meant for programs which only accept short options.
+    /// Note that without [`OptionsEverywhere`](OptFlags::OptionsEverywhere)
+    /// flag the first argument like `--foo` stops option parsing, like
+    /// any other non-option argument.
+    DisallowLongOptions,
+
+    /// Don't parse short options at all. Command-line arguments that
+    /// look like short options (`-f`) are parsed as other arguments
+    /// (non-options).
+    ///
+    /// This is meant for programs which only accept long options. See
+    /// also [`DisallowLongOptions`](OptFlags::DisallowLongOptions).
+    DisallowShortOptions,
+
+    /// Stop option parsing at the first unknown option. The unknown
+    /// option is collected to [`Args::unknown`] field as usual but the
+    /// whole command-line argument which had it, and all arguments
+    /// after it, are parsed as other arguments (non-options). For
+    /// example, with unknown option `-x` the argument `-vx` goes to
+    /// [`Args::other`] field and known option `-v` in the same argument
+    /// is not collected to [`Args::options`] field, so it is not
+    /// handled twice.
+    ///
+    /// This is useful for programs which pass unknown options and the
+    /// rest of the command line to some other program.
+    StopAtUnknown,
+
+    /// Stop option parsing at the first unknown option. The unknown
+    /// option is collected to [`Args::unknown`] field as usual and all
+    /// command-line arguments after the one which had it are parsed as
+    /// other arguments (non-options). Unlike with
+    /// [`StopAtUnknown`](OptFlags::StopAtUnknown) flag the argument
+    /// which had the unknown option does not go to [`Args::other`]
+    /// field. If the unknown option was in a series of short options,
+    /// the rest of the series goes to [`Args::other`] field as a
+    /// separate argument. For example, with unknown option `-x` the
+    /// argument `-vxf` gives option `-v`, unknown option `x` and other
+    /// argument `-f`.
+    ///
+    /// This is useful for strict programs which don't want to process
+    /// any options after an unknown option. If this flag and
+    /// [`StopAtUnknown`](OptFlags::StopAtUnknown) flag are both set,
+    /// the latter takes effect.
+    StopAtFirstUnknown,
+
+    /// Don't collect unknown options. Unknown options are skipped and
+    /// [`Args::unknown`] field stays empty. The limit set with
+    /// [`OptSpecs::limit_unknown_options`] method has no effect.
+    ///
+    /// This is useful for wrapper programs which don't care about
+    /// options they don't know.
+    IgnoreUnknown,
+
+    /// Ignore command-line arguments which start with `#` character,
+    /// like comments in shell scripts. Such arguments don't appear in
+    /// [`Args::other`] or [`Args::unknown`] fields, and this applies
+    /// also to arguments after the option terminator `--`. Option
+    /// values are not affected: in `--file #foo` the `#foo` is
+    /// option's value if the option requires a value.
+    ///
+    /// This is useful for argument lists read from files.
+    ShellStyleComments,
+
+    /// Parse command-line arguments which look like negative numbers
+    /// as other arguments (non-options). An argument is considered a
+    /// negative number if it starts with `-` character and the next
+    /// character is an ASCII digit, for example, `-1` or `-3.14`.
+    /// Without this flag such argument is parsed as short options
+    /// (`-1` is short option `1`).
+    ///
+    /// Note that negative numbers stop option parsing like any other
+    /// non-option argument unless
+    /// [`OptionsEverywhere`](OptFlags::OptionsEverywhere) flag is set.
+    /// Option values are not affected: in `-n -5` the `-5` is option's
+    /// value if the option requires a value.
+    AllowNegativeNumbers,
+
+    /// Accept `=` character between a short option and its value, like
+    /// `-f=value`. The `=` is removed so the value is `value`. Without
+    /// this flag the value would be `=value` because everything after
+    /// the option character is the value.
+    ///
+    /// An empty value after `=` is given with `-f=`. Then an option
+    /// which requires a value does not take the next command-line
+    /// argument as its value, and with value types
+    /// [`OptValue::RequiredNonEmpty`] and
+    /// [`OptValue::OptionalNonEmpty`] the option has no value at all,
+    /// as usual for empty values.
+    AllowShortOptionEquals,
 }
 
 impl OptSpecs {
@@ -443,12 +640,43 @@ impl OptSpecs {
         Self {
             options: Vec::with_capacity(5),
             flags: Vec::with_capacity(2),
+            dependencies: Vec::new(),
+            name_dependencies: Vec::new(),
+            exclusive_groups: Vec::new(),
+            version: None,
+            pre_parsed: Vec::new(),
+            defaults: Vec::new(),
+            long_value_separator: '=',
             option_limit: COUNTER_LIMIT,
             other_limit: COUNTER_LIMIT,
             unknown_limit: COUNTER_LIMIT,
         }
     }
 
+    /// Create a new [`OptSpecs`] with the same flags and limits.
+    ///
+    /// The created instance has the same flags (see
+    /// [`flag`](OptSpecs::flag) method), the same long option value
+    /// separator (see
+    /// [`long_value_separator`](OptSpecs::long_value_separator) method)
+    /// and the same limits (see
+    /// [`limit_options`](OptSpecs::limit_options) and other methods)
+    /// as `self` but it does not have any option specifications. Also
+    /// option dependencies and groups, which refer to options, are not
+    /// copied. This is useful for building specifications for
+    /// sub-commands which share parser's behavior but have their own
+    /// options.
+    pub fn clone_empty(&self) -> OptSpecs {
+        Self {
+            flags: self.flags.clone(),
+            long_value_separator: self.long_value_separator,
+            option_limit: self.option_limit,
+            other_limit: self.other_limit,
+            unknown_limit: self.unknown_limit,
+            ..Self::new()
+        }
+    }
+
     /// Add an option specification for [`OptSpecs`].
     ///
     /// The method requires three arguments:
@@ -482,36 +710,402 @@ impl OptSpecs {
     ///     the enum's documentation for more information.
     ///
     /// The return value is the same struct instance which was modified.
-    pub fn option(mut self, id: &str, name: &str, value_type: OptValue) -> Self {
+    /// Also see method [`try_option`](OptSpecs::try_option) which
+    /// returns an error instead of panicking.
+    pub fn option(self, id: &str, name: &str, value_type: OptValue) -> Self {
+        match self.try_option(id, name, value_type) {
+            Ok(specs) => specs,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Add an option specification for [`OptSpecs`] or fail.
+    ///
+    /// This is the same as [`option`](OptSpecs::option) method but
+    /// this method doesn't panic for invalid arguments. The return
+    /// value is `Ok` with the same struct instance which was modified
+    /// or `Err` with a variant of enum [`OptionSpecError`]. This is
+    /// useful when option specifications come from run-time data, like
+    /// a configuration file.
+    pub fn try_option(
+        mut self,
+        id: &str,
+        name: &str,
+        value_type: OptValue,
+    ) -> Result<Self, OptionSpecError> {
+        self.check_new_option(id, name, false)?;
+        self.push_option(id, name, value_type, false);
+        Ok(self)
+    }
+
+    /// Add a short option specification for [`OptSpecs`].
+    ///
+    /// This is like [`option`](OptSpecs::option) method but option's
+    /// name is given as a character. So the method always defines a
+    /// short option.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_short_only(self, id: &str, char_name: char, value_type: OptValue) -> Self {
+        let mut buf = [0; 4];
+        self.option(id, char_name.encode_utf8(&mut buf), value_type)
+    }
+
+    /// Add a long option specification for [`OptSpecs`].
+    ///
+    /// This is like [`option`](OptSpecs::option) method but the method
+    /// panics if the `name` is not a long option name, that is, if it
+    /// has fewer than two characters.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_long_only(self, id: &str, name: &str, value_type: OptValue) -> Self {
         assert!(
-            id.chars().count() > 0,
-            "Option's \"id\" must be at least 1 character long."
+            name.chars().count() > 1,
+            "Long option's \"name\" must be at least 2 characters long."
         );
+        self.option(id, name, value_type)
+    }
 
-        match name.chars().count() {
-            0 => panic!("Option's \"name\" must be at least 1 character long."),
-            1 => assert!(
-                parser::is_valid_short_option_name(name),
-                "Not a valid short option name."
-            ),
-            _ => assert!(
-                parser::is_valid_long_option_name(name),
-                "Not a valid long option name."
+    /// Add a long option name for an existing short option.
+    ///
+    /// Find the option specification whose name is `short_name` and add
+    /// a new long option `long_alias` with the same identifier, value
+    /// type, range and category. This makes it easy to add short and
+    /// long option pairs:
+    ///
+    /// ```
+    /// use just_getopt::{OptSpecs, OptValue};
+    ///
+    /// let specs = OptSpecs::new()
+    ///     .option_short_only("file", 'f', OptValue::Required)
+    ///     .option_long_alias('f', "file");
+    /// ```
+    ///
+    /// This method panics if there is no short option `short_name`.
+    /// Otherwise the same rules and panics apply as in
+    /// [`option_long_only`](OptSpecs::option_long_only) method.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_long_alias(self, short_name: char, long_alias: &str) -> Self {
+        let mut buf = [0; 4];
+        let short_name: &str = short_name.encode_utf8(&mut buf);
+        let (id, value_type, range, category) = match self
+            .options
+            .iter()
+            .find(|s| !s.no_prefix && s.name == short_name)
+        {
+            Some(spec) => (
+                spec.id.clone(),
+                spec.value_type.clone(),
+                spec.range,
+                spec.category.clone(),
             ),
+            None => panic!("No short option to alias ({}).", short_name),
+        };
+        let mut specs = self.option_long_only(&id, long_alias, value_type);
+        if let Some(spec) = specs.options.last_mut() {
+            spec.range = range;
+            spec.category = category;
+        }
+        specs
+    }
+
+    /// Add an option specification without prefix for [`OptSpecs`].
+    ///
+    /// This is like [`option`](OptSpecs::option) method but the option
+    /// is entered in the command line as a bare word without `-` or
+    /// `--` prefix, like a command or a mode (for example, `build`). Such
+    /// word is recognized as an option only when it is exactly the
+    /// same as `name`. Options without prefix are matched after normal
+    /// options, so an argument which looks like a normal option is
+    /// never matched here. Option without prefix does not stop option
+    /// parsing.
+    ///
+    /// If option requires a value (see [`OptValue`]) the next
+    /// command-line argument is parsed as option's value. Optional
+    /// values can't be entered at all because there is no way to
+    /// attach a value to a bare word.
+    ///
+    /// The `name` must be unique among all options, including normal
+    /// options. This method panics if the `name` is empty, has space
+    /// characters or has `-` as its first character.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_no_prefix(mut self, id: &str, name: &str, value_type: OptValue) -> Self {
+        if let Err(e) = self.check_new_option(id, name, true) {
+            panic!("{}", e);
+        }
+        self.push_option(id, name, value_type, true);
+        self
+    }
+
+    fn check_new_option(
+        &self,
+        id: &str,
+        name: &str,
+        no_prefix: bool,
+    ) -> Result<(), OptionSpecError> {
+        if id.is_empty() {
+            return Err(OptionSpecError::EmptyId);
+        }
+
+        let valid = match name.chars().count() {
+            0 => return Err(OptionSpecError::EmptyName),
+            _ if no_prefix => parser::is_valid_no_prefix_option_name(name),
+            1 => parser::is_valid_short_option_name(name),
+            _ => parser::is_valid_long_option_name(name),
+        };
+        if !valid {
+            return Err(OptionSpecError::InvalidName(name.to_string()));
         }
 
         if self.options.iter().any(|o| o.name == name) {
-            panic!("No duplicates allowed for option's \"name\".")
+            return Err(OptionSpecError::DuplicateName(name.to_string()));
         }
+        Ok(())
+    }
 
+    fn push_option(&mut self, id: &str, name: &str, value_type: OptValue, no_prefix: bool) {
         self.options.push(OptSpec {
             id: id.to_string(),
             name: name.to_string(),
             value_type,
+            range: None,
+            category: Vec::new(),
+            no_prefix,
         });
+    }
+
+    /// Create [`OptSpecs`] from program's help text.
+    ///
+    /// Option specifications are read from a simple help text format
+    /// where each option line starts with `-` character, like this:
+    ///
+    /// ```text
+    /// -f, --file FILE     file to process
+    /// -c, --color[=WHEN]  use colors
+    /// -v, --verbose       enable verbosity
+    /// ```
+    ///
+    /// Option names on the same line (separated with commas or spaces)
+    /// get the same identifier, which is the first long option name or
+    /// the short option name if there is no long option. A value
+    /// placeholder after a name (`FILE` or `--file=FILE`) means that
+    /// the options require a value ([`OptValue::Required`]). A
+    /// placeholder in brackets (`[WHEN]` or `--color[=WHEN]`) means an
+    /// optional value ([`OptValue::Optional`]). Without a placeholder
+    /// options don't accept a value ([`OptValue::None`]).
+    ///
+    /// Two or more space characters end the option part of the line
+    /// and the rest is description, which is ignored. Lines which don't
+    /// start with `-` (after leading white space) are ignored too.
+    ///
+    /// The return value is `Ok(OptSpecs)` or `Err` with a variant of
+    /// enum [`OptionSpecError`]. Method
+    /// [`try_option`](OptSpecs::try_option) is called for each option
+    /// so the same rules apply. For example, an option name which is
+    /// listed twice in the help text is an error. This method does not
+    /// panic.
+    pub fn from_help_string(s: &str) -> Result<Self, OptionSpecError> {
+        let mut specs = Self::new();
+        for line in s.lines() {
+            for (id, name, value_type) in help::parse_help_line(line) {
+                specs = specs.try_option(&id, &name, value_type)?;
+            }
+        }
+        Ok(specs)
+    }
+
+    /// Add an option specification with a value from the environment.
+    ///
+    /// This is like [`option`](OptSpecs::option) method but the method
+    /// also reads environment variable `env_var` immediately. If the
+    /// variable is set (and it is valid Unicode) an [`Opt`] struct for
+    /// this option is stored and [`getopt`](OptSpecs::getopt) method
+    /// puts it before the options parsed from the command line. So
+    /// with methods like [`Args::options_value_last`] the command line
+    /// overrides the environment.
+    ///
+    /// The variable's value is handled according to `value_type` (see
+    /// [`OptValue`]). For [`OptValue::None`] the value is ignored and
+    /// the variable only needs to be set. The [`Opt::source_index`]
+    /// field of the stored option is [`usize::MAX`]. The option counts
+    /// towards the limit set with
+    /// [`limit_options`](OptSpecs::limit_options) method.
+    ///
+    /// This method is available only with the crate feature `std`.
+    ///
+    /// The return value is the same struct instance which was modified.
+    #[cfg(feature = "std")]
+    pub fn option_parse_env(
+        self,
+        id: &str,
+        name: &str,
+        value_type: OptValue,
+        env_var: &str,
+    ) -> Self {
+        let (value_required, non_empty) = match value_type {
+            OptValue::None | OptValue::Optional => (false, false),
+            OptValue::OptionalNonEmpty => (false, true),
+            OptValue::Required => (true, false),
+            OptValue::RequiredNonEmpty => (true, true),
+        };
+        let accepts_value = value_type != OptValue::None;
+
+        let mut specs = self.option(id, name, value_type);
+
+        if let Ok(value) = std::env::var(env_var) {
+            specs.pre_parsed.push(Opt {
+                id: id.to_string(),
+                name: name.to_string(),
+                value_required,
+                value: Some(value).filter(|v| accepts_value && !(non_empty && v.is_empty())),
+                source_index: usize::MAX,
+                no_prefix: false,
+            });
+        }
+        specs
+    }
+
+    /// Remove an option specification.
+    ///
+    /// Remove the option whose name is `name` (without `-` or `--`
+    /// prefix). A value read from the environment for the option (see
+    /// `OptSpecs::option_parse_env`) is removed too. Nothing happens if
+    /// there is no such option. The same name can be added again
+    /// later.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn remove_option(mut self, name: &str) -> Self {
+        self.options.retain(|s| s.name != name);
+        self.pre_parsed.retain(|o| o.name != name);
+        self
+    }
+
+    /// Add several option specifications for [`OptSpecs`].
+    ///
+    /// The `specs` argument is of any type that implements trait
+    /// [`IntoIterator`] and whose items are `(id, name, value_type)`
+    /// tuples, for example an array. Method [`option`](OptSpecs::option)
+    /// is called for each tuple so the same rules and panics apply.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_list<'a, I>(mut self, specs: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, &'a str, OptValue)>,
+    {
+        for (id, name, value_type) in specs {
+            self = self.option(id, name, value_type);
+        }
+        self
+    }
+
+    /// Add option specifications from a map of `id -> (name,
+    /// value_type)` entries.
+    ///
+    /// This is meant for programs which build their options at run
+    /// time, for example when plugins register their own options. The
+    /// `opts` argument is of any type that implements trait
+    /// [`IntoIterator`] and whose items are `(id, (name, value_type))`
+    /// tuples, like `HashMap<&str, (&str, OptValue)>` or
+    /// [`BTreeMap`]. Method [`option`](OptSpecs::option) is called for
+    /// each entry so the same rules and panics apply. Note that the
+    /// iteration order of `HashMap` is unspecified so options are
+    /// added in unspecified order.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn with_runtime_options<'a, I>(mut self, opts: I) -> Self
+    where
+        I: IntoIterator<Item = (&'a str, (&'a str, OptValue))>,
+    {
+        for (id, (name, value_type)) in opts {
+            self = self.option(id, name, value_type);
+        }
         self
     }
 
+    /// Add an option specification in a category.
+    ///
+    /// This is like [`option`](OptSpecs::option) method but the option
+    /// is also put in a category. The `category` argument is a path of
+    /// nested category names, like `&["output", "format"]`, where the
+    /// first name is the top-level category. Categories don't affect
+    /// parsing. They are meant for organizing options, for example in
+    /// program's help message. See method
+    /// [`iter_specs_by_category`](OptSpecs::iter_specs_by_category).
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_in_category(
+        self,
+        category: &[&str],
+        id: &str,
+        name: &str,
+        value_type: OptValue,
+    ) -> Self {
+        let mut specs = self.option(id, name, value_type);
+        if let Some(spec) = specs.options.last_mut() {
+            spec.category = category.iter().map(|c| c.to_string()).collect();
+        }
+        specs
+    }
+
+    /// Iterate the option specifications.
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty) and each item is a reference to [`OptSpec`]
+    /// struct. Items are in the same order as the options were added.
+    pub fn iter_specs(&self) -> impl DoubleEndedIterator<Item = &OptSpec> {
+        self.options.iter()
+    }
+
+    /// Iterate option specifications grouped by category.
+    ///
+    /// Options are put in categories with
+    /// [`option_in_category`](OptSpecs::option_in_category) method.
+    /// Each item of the returned iterator is a tuple `(category,
+    /// specs)` where `category` is the full category path and `specs`
+    /// are references to [`OptSpec`] structs in that category, in the
+    /// order they were added. Categories are in the order they were
+    /// first used. Options without a category are in a group whose
+    /// category path is empty.
+    pub fn iter_specs_by_category(&self) -> impl Iterator<Item = (Vec<&str>, Vec<&OptSpec>)> {
+        let mut groups: Vec<(Vec<&str>, Vec<&OptSpec>)> = Vec::new();
+
+        for spec in &self.options {
+            let path: Vec<&str> = spec.category.iter().map(|c| c.as_str()).collect();
+            match groups.iter_mut().find(|(p, _)| *p == path) {
+                Some((_, specs)) => specs.push(spec),
+                None => groups.push((path, alloc::vec![spec])),
+            }
+        }
+        groups.into_iter()
+    }
+
+    /// Add an option specification for an integer value with a range.
+    ///
+    /// This is like [`option`](OptSpecs::option) method with value type
+    /// [`OptValue::Required`] but option's value must also be an integer
+    /// between `min` and `max` (inclusive). The value is not checked
+    /// during parsing and it is stored in [`Opt::value`] field as usual.
+    /// Use [`Args::range_violations`] method to find values which are
+    /// not integers or are outside the range.
+    ///
+    /// This method will panic if `min` is greater than `max`. See
+    /// [`option`](OptSpecs::option) method for other panics.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_range(self, id: &str, name: &str, min: i64, max: i64) -> Self {
+        assert!(
+            min <= max,
+            "Option's range minimum must not be greater than maximum."
+        );
+
+        let mut specs = self.option(id, name, OptValue::Required);
+        if let Some(spec) = specs.options.last_mut() {
+            spec.range = Some((min, max));
+        }
+        specs
+    }
+
     /// Add a flag that changes parser's behavior.
     ///
     /// Method's only argument `flag` is a variant of enum [`OptFlags`]
@@ -526,10 +1120,127 @@ impl OptSpecs {
         self
     }
 
+    /// Add version options and store program's version string.
+    ///
+    /// This method adds options `-V` and `--version` with identifier
+    /// `version` (see [`option`](OptSpecs::option) method) and stores
+    /// the `version` string. After parsing, method
+    /// `Args::print_version_if_requested` (crate feature `std`) prints
+    /// the version string if either option was given.
+    ///
+    /// The method panics if options `V` or `version` have already been
+    /// added.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn with_version_info(self, version: &str) -> Self {
+        let mut specs = self.option("version", "V", OptValue::None).option(
+            "version",
+            "version",
+            OptValue::None,
+        );
+        specs.version = Some(version.to_string());
+        specs
+    }
+
+    /// Add a dependency between two options.
+    ///
+    /// The dependency means that if an option with identifier
+    /// `dependent_id` is given in the command line then an option with
+    /// identifier `required_id` must be given too. For example,
+    /// `--output-encoding` might depend on `--output`. Dependencies are
+    /// not checked during parsing. Use [`Args::check_dependencies`]
+    /// method after parsing.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_depends_on(mut self, dependent_id: &str, required_id: &str) -> Self {
+        let dependency = (dependent_id.to_string(), required_id.to_string());
+        if !self.dependencies.contains(&dependency) {
+            self.dependencies.push(dependency);
+        }
+        self
+    }
+
+    /// Add a dependency from an option name to an identifier.
+    ///
+    /// This is similar to [`option_depends_on`](OptSpecs::option_depends_on)
+    /// method but the dependent option is given by its name (the
+    /// command-line form without `-` or `--` prefix), not by its
+    /// identifier. If option `option_name` is given in the command line
+    /// then an option with identifier `required_id` must be given too.
+    /// For example, `--output-json` might require some option with
+    /// identifier `output`, while other options with the same identifier
+    /// as `--output-json` have no such requirement. Use
+    /// [`Args::check_dependencies`] method after parsing.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_requires_id(mut self, option_name: &str, required_id: &str) -> Self {
+        let dependency = (option_name.to_string(), required_id.to_string());
+        if !self.name_dependencies.contains(&dependency) {
+            self.name_dependencies.push(dependency);
+        }
+        self
+    }
+
+    /// Add a group of mutually exclusive options.
+    ///
+    /// The `ids` argument is a slice of option identifiers. Only one of
+    /// them may be given in the command line. For example, `--json` and
+    /// `--xml` output formats could be mutually exclusive. The same
+    /// identifier may be given several times in the command line. Groups
+    /// are not checked during parsing. Use
+    /// [`Args::verify_exclusive_groups`] method after parsing.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn option_exclusive(mut self, ids: &[&str]) -> Self {
+        self.exclusive_groups
+            .push(ids.iter().map(|id| id.to_string()).collect());
+        self
+    }
+
     fn is_flag(&self, flag: OptFlags) -> bool {
         self.flags.contains(&flag)
     }
 
+    /// Set default values for options.
+    ///
+    /// The `defaults` argument is a slice of `(id, value)` tuples. After
+    /// parsing, [`getopt`](OptSpecs::getopt) method adds an [`Opt`]
+    /// struct with the default value for each identifier which was not
+    /// given in the command line. The added options are at the end of
+    /// [`Args::options`] field. Their [`Opt::name`] field is the name of
+    /// the first option with the same identifier (or the identifier if
+    /// there is no such option), [`Opt::value_required`] field is
+    /// `true` and [`Opt::source_index`] field is [`usize::MAX`]. If the
+    /// same identifier is given again its default value is replaced.
+    /// Default values count towards the limit set with
+    /// [`limit_options`](OptSpecs::limit_options) method, so they are
+    /// not added if the limit is already reached.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn apply_defaults(mut self, defaults: &[(&str, &str)]) -> Self {
+        for (id, value) in defaults {
+            match self.defaults.iter_mut().find(|(i, _)| i == id) {
+                Some(d) => d.1 = value.to_string(),
+                None => self.defaults.push((id.to_string(), value.to_string())),
+            }
+        }
+        self
+    }
+
+    /// Set the character which separates long option's name and value.
+    ///
+    /// By default the `=` character separates long option's name and
+    /// value in the command line (`--foo=VALUE`). This method changes
+    /// the separator to `sep`, for example, `:` for `--foo:VALUE`. Then
+    /// the `=` character is no longer a separator. Long option names
+    /// which contain the separator character can't be matched.
+    ///
+    /// The return value is the same struct instance which was modified.
+    pub fn long_value_separator(mut self, sep: char) -> Self {
+        self.long_value_separator = sep;
+        self
+    }
+
     /// Maximum number of valid options.
     ///
     /// Method's argument `limit` sets the maximum number of valid
@@ -537,6 +1248,13 @@ impl OptSpecs {
     /// This doesn't include unknown options (see
     /// [`limit_unknown_options`](OptSpecs::limit_unknown_options)).
     ///
+    /// The limit applies to the whole [`Args::options`] field,
+    /// including options from the environment (see
+    /// `OptSpecs::option_parse_env`) and default values (see
+    /// [`apply_defaults`](OptSpecs::apply_defaults)). Options from the
+    /// environment come first and default values last, so they are
+    /// counted in that order too.
+    ///
     /// The return value is the same struct instance which was modified.
     pub fn limit_options(mut self, limit: u32) -> Self {
         self.option_limit = limit;
@@ -577,6 +1295,25 @@ impl OptSpecs {
         self
     }
 
+    /// Return a one-line summary of option specifications.
+    ///
+    /// The summary is like a synopsis line in program's help message,
+    /// for example `[-h|--help] [-f|--file <VALUE>] [--verbose[=VALUE]]`.
+    /// Options which have the same identifier are grouped together in
+    /// the same brackets, in the order they were added. Options which
+    /// require a value are followed by ` <VALUE>`. Options which accept
+    /// an optional value are followed by `[=VALUE]`, or `[VALUE]` if
+    /// the group has only short options. The value type of the first
+    /// option in the group is used. Options without prefix (see
+    /// [`option_no_prefix`](OptSpecs::option_no_prefix)) are shown as
+    /// bare words.
+    ///
+    /// This is the same as formatting with the [`Display`](fmt::Display)
+    /// implementation of [`OptSpecs`].
+    pub fn summary_string(&self) -> String {
+        self.to_string()
+    }
+
     /// Getopt-parse an iterable item as command line arguments.
     ///
     /// This method's argument `args` is of any type that implements
@@ -592,21 +1329,106 @@ impl OptSpecs {
         I: IntoIterator<Item = S>,
         S: ToString,
     {
-        parser::parse(self, args.into_iter().map(|i| i.to_string()))
+        let mut parsed = parser::parse(self, args.into_iter().map(|i| i.to_string()));
+
+        parsed.options.splice(0..0, self.pre_parsed.iter().cloned());
+
+        for (id, value) in &self.defaults {
+            if parsed.option_exists(id) {
+                continue;
+            }
+            let (name, no_prefix) = match self.options.iter().find(|s| &s.id == id) {
+                Some(spec) => (spec.name.clone(), spec.no_prefix),
+                None => (id.clone(), false),
+            };
+            parsed.options.push(Opt {
+                id: id.clone(),
+                name,
+                value_required: true,
+                value: Some(value.clone()),
+                source_index: usize::MAX,
+                no_prefix,
+            });
+        }
+
+        parsed.options.truncate(self.option_limit as usize);
+        parsed
+    }
+
+    /// Getopt-parse command line and exit the program on errors.
+    ///
+    /// This is like [`getopt`](OptSpecs::getopt) method but after
+    /// parsing this method checks if there were unknown options or
+    /// options with missing required value. If so, this method prints
+    /// error messages to the standard error output, including a line
+    /// `Try 'PROGRAM --help' for more information.` where `PROGRAM` is
+    /// the `program_name` argument. Then the program exits with exit
+    /// code 1 (see [`std::process::exit`]).
+    ///
+    /// If there were no errors the return value is an [`Args`] struct
+    /// like in [`getopt`](OptSpecs::getopt) method.
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn parse_args_or_exit<I, S>(&self, args: I, program_name: &str) -> Args
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let parsed = self.getopt(args);
+        let errors = parsed.validate();
+
+        for e in &errors {
+            std::eprintln!("{}: {}", program_name, e);
+        }
+
+        if !errors.is_empty() {
+            std::eprintln!("Try '{} --help' for more information.", program_name);
+            std::process::exit(1);
+        }
+
+        parsed
+    }
+
+    /// Getopt-parse command line and fail on errors.
+    ///
+    /// This is like [`getopt`](OptSpecs::getopt) method but after
+    /// parsing this method checks if there were unknown options or
+    /// options with missing required value (see [`Args::validate`]). If
+    /// so, the return value is `Err` with a vector of all errors as
+    /// [`ParseError`] enums. Otherwise the return value is `Ok` with
+    /// the [`Args`] struct.
+    ///
+    /// Method [`getopt`](OptSpecs::getopt) does not fail. It records
+    /// the problems in [`Args::unknown`] field and in the options
+    /// listed by [`Args::required_value_missing`] method.
+    pub fn getopt_strict<I, S>(&self, args: I) -> Result<Args, Vec<ParseError>>
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        let parsed = self.getopt(args);
+        let errors = parsed.validate();
+
+        if errors.is_empty() {
+            Ok(parsed)
+        } else {
+            Err(errors)
+        }
     }
 
     fn get_short_option_match(&self, name: &str) -> Option<&OptSpec> {
         if name.chars().count() != 1 {
             return None;
         }
-        self.options.iter().find(|e| e.name == name)
+        self.options.iter().find(|e| !e.no_prefix && e.name == name)
     }
 
     fn get_long_option_match(&self, name: &str) -> Option<&OptSpec> {
         if name.chars().count() < 2 {
             return None;
         }
-        self.options.iter().find(|e| e.name == name)
+        self.options.iter().find(|e| !e.no_prefix && e.name == name)
     }
 
     fn get_long_option_prefix_match(&self, name: &str) -> Option<&OptSpec> {
@@ -620,7 +1442,7 @@ impl OptSpecs {
 
         let mut result = None;
 
-        for e in &self.options {
+        for e in self.options.iter().filter(|e| !e.no_prefix) {
             if e.name.starts_with(name) {
                 if result.is_none() {
                     result = Some(e);
@@ -631,6 +1453,10 @@ impl OptSpecs {
         }
         result
     }
+
+    fn get_no_prefix_match(&self, name: &str) -> Option<&OptSpec> {
+        self.options.iter().find(|e| e.no_prefix && e.name == name)
+    }
 }
 
 impl Default for OptSpecs {
@@ -639,6 +1465,65 @@ impl Default for OptSpecs {
     }
 }
 
+/// Format option specifications as a one-line summary.
+///
+/// See method [`OptSpecs::summary_string`] for the format.
+impl fmt::Display for OptSpecs {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut seen: Vec<&str> = Vec::new();
+
+        for spec in &self.options {
+            if seen.contains(&spec.id.as_str()) {
+                continue;
+            }
+            if !seen.is_empty() {
+                f.write_str(" ")?;
+            }
+            seen.push(&spec.id);
+
+            let group: Vec<&OptSpec> = self.options.iter().filter(|s| s.id == spec.id).collect();
+            let has_long = group
+                .iter()
+                .any(|s| !s.no_prefix && s.name.chars().count() > 1);
+            let has_prefixed = group.iter().any(|s| !s.no_prefix);
+
+            f.write_str("[")?;
+            for (i, s) in group.iter().enumerate() {
+                if i > 0 {
+                    f.write_str("|")?;
+                }
+                f.write_str(&s.display_name())?;
+            }
+            f.write_str(match (&spec.value_type, has_long, has_prefixed) {
+                (OptValue::None, _, _) => "",
+                (OptValue::Required | OptValue::RequiredNonEmpty, _, _) => " <VALUE>",
+                (_, true, _) => "[=VALUE]",
+                (_, false, true) => "[VALUE]",
+                (_, false, false) => "",
+            })?;
+            f.write_str("]")?;
+        }
+        Ok(())
+    }
+}
+
+/// Create [`OptSpecs`] from `(id, name, value_type)` tuples.
+///
+/// Method [`OptSpecs::option`] is called for each tuple so the same
+/// rules and panics apply. Also see method [`OptSpecs::option_list`].
+impl FromIterator<(String, String, OptValue)> for OptSpecs {
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = (String, String, OptValue)>,
+    {
+        let mut specs = Self::new();
+        for (id, name, value_type) in iter {
+            specs = specs.option(&id, &name, value_type);
+        }
+        specs
+    }
+}
+
 /// Parsed command line in organized form.
 ///
 /// Instances of this struct are usually created with
@@ -649,7 +1534,7 @@ impl Default for OptSpecs {
 /// Programmers can use the parsed output ([`Args`] struct) any way they
 /// like. There are some methods for convenience.
 
-#[derive(Debug, PartialEq)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct Args {
     /// A vector of valid command-line options.
     ///
@@ -724,6 +1609,26 @@ impl Args {
             .filter(|opt| opt.value_required && opt.value.is_none())
     }
 
+    /// Find the first option with missing required value.
+    ///
+    /// This is the same as
+    /// [`required_value_missing`](Args::required_value_missing)`().next()`.
+    /// The return value is `None` if there are no options with missing
+    /// required value.
+    pub fn required_value_missing_first(&self) -> Option<&Opt> {
+        self.required_value_missing().next()
+    }
+
+    /// Find the last option with missing required value.
+    ///
+    /// This is similar to
+    /// [`required_value_missing_first`](Args::required_value_missing_first)
+    /// method but this returns the last match in command-line
+    /// arguments' order.
+    pub fn required_value_missing_last(&self) -> Option<&Opt> {
+        self.required_value_missing().next_back()
+    }
+
     /// Return boolean whether option with the given `id` exists.
     ///
     /// This is functionally the same as
@@ -732,9 +1637,33 @@ impl Args {
         self.options.iter().any(|opt| opt.id == id)
     }
 
-    /// Find all options with the given `id`.
+    /// Return boolean whether option with any of the given `ids` exists.
     ///
-    /// Find all options which have the identifier `id`. (Option
+    /// The return value is `true` if at least one identifier in `ids`
+    /// matches an option in [`Args::options`] field.
+    pub fn option_exists_any(&self, ids: &[&str]) -> bool {
+        ids.iter().any(|id| self.option_exists(id))
+    }
+
+    /// Return boolean whether options with all the given `ids` exist.
+    ///
+    /// The return value is `true` if every identifier in `ids` matches
+    /// an option in [`Args::options`] field.
+    pub fn option_exists_all(&self, ids: &[&str]) -> bool {
+        ids.iter().all(|id| self.option_exists(id))
+    }
+
+    /// Return boolean whether any valid options were found.
+    ///
+    /// This is the same as `!args.options.is_empty()` but may express
+    /// the intent more clearly.
+    pub fn has_any_option(&self) -> bool {
+        !self.options.is_empty()
+    }
+
+    /// Find all options with the given `id`.
+    ///
+    /// Find all options which have the identifier `id`. (Option
     /// identifiers have been defined in [`OptSpecs`] struct before
     /// parsing.)
     ///
@@ -744,10 +1673,54 @@ impl Args {
     /// are in the same order as in the parsed command line. You can
     /// collect the iterator to a vector by applying method
     /// [`collect`](core::iter::Iterator::collect)`::<Vec<&Opt>>()`.
-    pub fn options_all<'a>(&'a self, id: &'a str) -> impl DoubleEndedIterator<Item = &'a Opt> {
+    pub fn options_all(&self, id: &str) -> impl DoubleEndedIterator<Item = &Opt> {
+        let id = id.to_string();
         self.options.iter().filter(move |opt| opt.id == id)
     }
 
+    /// Find all names and values for options with the given `id`.
+    ///
+    /// This is similar to [`options_value_all`](Args::options_value_all)
+    /// method but this method also includes options which don't have a
+    /// value. So the caller sees every occurrence of the option.
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty, if no matches) and each item is a tuple `(name,
+    /// value)`. The `name` is option's name in the command line (see
+    /// [`Opt::name`]) and `value` is `None` if the option didn't have a
+    /// value or `Some(&str)` if it had. Items are in the same order as
+    /// in the parsed command line.
+    pub fn option_group_values(
+        &self,
+        id: &str,
+    ) -> impl DoubleEndedIterator<Item = (&str, Option<&str>)> {
+        let id = id.to_string();
+        self.options
+            .iter()
+            .filter(move |opt| opt.id == id)
+            .map(|opt| (opt.name.as_str(), opt.value.as_deref()))
+    }
+
+    /// Find all options with the given `id` which don't have a value.
+    ///
+    /// Find all options which have the identifier `id` and which don't
+    /// have a value assigned (their [`Opt::value`] field is `None`). For
+    /// example, `--file` without a value is found but `--file foo` and
+    /// `--file=` are not. Together with [`Opt::value_required`] field
+    /// this finds options with missing required value. Also see method
+    /// [`required_value_missing`](Args::required_value_missing).
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty, if no matches) and each item is a reference to
+    /// [`Opt`] struct in the original [`Args::options`] field. Items
+    /// are in the same order as in the parsed command line.
+    pub fn options_not_having_value(&self, id: &str) -> impl DoubleEndedIterator<Item = &Opt> {
+        let id = id.to_string();
+        self.options
+            .iter()
+            .filter(move |opt| opt.id == id && opt.value.is_none())
+    }
+
     /// Find the first option with the given `id`.
     ///
     /// Find and return the first match for option `id` in command-line
@@ -788,10 +1761,8 @@ impl Args {
     /// line. You can collect the iterator to a vector by applying
     /// method
     /// [`collect`](core::iter::Iterator::collect)`::<Vec<&String>>()`.
-    pub fn options_value_all<'a>(
-        &'a self,
-        id: &'a str,
-    ) -> impl DoubleEndedIterator<Item = &'a String> {
+    pub fn options_value_all(&self, id: &str) -> impl DoubleEndedIterator<Item = &String> {
+        let id = id.to_string();
         self.options.iter().filter_map(move |opt| {
             if opt.id == id {
                 opt.value.as_ref()
@@ -849,830 +1820,4720 @@ impl Args {
             None => None,
         }
     }
-}
 
-/// Structured option information.
-///
-/// This [`Opt`] struct represents organized information about single
-/// command-line option. Instances of this struct are usually created by
-/// [`OptSpecs::getopt`] method which returns an [`Args`] struct which
-/// have these [`Opt`] structs inside.
-///
-/// A programmer may need these when examining parsed command-line
-/// options. See the documentation of individual fields for more
-/// information. Also see [`Args`] struct and its methods.
+    /// Find the first non-empty value for given option `id`.
+    ///
+    /// This is similar to
+    /// [`options_value_first`](Args::options_value_first) method but
+    /// empty string values are skipped. For example, if the command
+    /// line has `--foo= --foo=bar` this method returns `"bar"` for the
+    /// option `foo`. The return value is `None` if no option with the
+    /// given `id` has a non-empty value.
+    pub fn option_value_first_non_empty<'a>(&'a self, id: &str) -> Option<&'a str> {
+        self.options
+            .iter()
+            .filter(|opt| opt.id == id)
+            .filter_map(|opt| opt.value.as_deref())
+            .find(|v| !v.is_empty())
+    }
 
-#[derive(Debug, PartialEq)]
-pub struct Opt {
-    /// Identifier for the option.
+    /// Find the last non-empty value for given option `id`.
     ///
-    /// Identifiers are defined with [`OptSpecs::option`] method before
-    /// parsing command-line arguments. After [`OptSpecs::getopt`]
-    /// parsing the same identifier is copied here and it confirms that
-    /// the option was indeed given in the command line.
-    pub id: String,
+    /// This is similar to
+    /// [`option_value_first_non_empty`](Args::option_value_first_non_empty)
+    /// method but this method finds and returns the last non-empty
+    /// value.
+    pub fn option_value_last_non_empty<'a>(&'a self, id: &str) -> Option<&'a str> {
+        self.options
+            .iter()
+            .rev()
+            .filter(|opt| opt.id == id)
+            .filter_map(|opt| opt.value.as_deref())
+            .find(|v| !v.is_empty())
+    }
 
-    /// Option's name in the parsed command line.
+    /// Parse all other arguments (non-options) to type `T`.
     ///
-    /// Option's name that was used in the command line. For short
-    /// options this is a single-character string. For long options the
-    /// name has more than one characters.
-    pub name: String,
+    /// Each string in [`Args::other`] field is parsed to type `T` with
+    /// [`FromStr`] trait's method `from_str`. This is useful when all
+    /// non-option arguments are expected to be of the same type, like
+    /// numbers.
+    ///
+    /// The return value is a vector of [`Result`] values, one for each
+    /// element in [`Args::other`] field and in the same order. Also see
+    /// method [`collect_other_all_parsed`](Args::collect_other_all_parsed).
+    pub fn collect_other_parsed<T: FromStr>(&self) -> Vec<Result<T, T::Err>> {
+        self.other.iter().map(|s| s.parse::<T>()).collect()
+    }
 
-    /// The option requires a value.
+    /// Parse all other arguments (non-options) to type `T` or fail.
     ///
-    /// `true` means that the option was defined with value type
-    /// [`OptValue::Required`]. See [`OptSpecs::flag`] method for
-    /// more information. This field does not guarantee that there
-    /// actually was a value for the option in the command line.
-    pub value_required: bool,
+    /// This is similar to
+    /// [`collect_other_parsed`](Args::collect_other_parsed) method but
+    /// this method stops at the first error. The return value is
+    /// `Ok(Vec<T>)` if all elements in [`Args::other`] field could be
+    /// parsed. Otherwise the return value is `Err` with the first parse
+    /// error.
+    pub fn collect_other_all_parsed<T: FromStr>(&self) -> Result<Vec<T>, T::Err> {
+        self.other.iter().map(|s| s.parse::<T>()).collect()
+    }
 
-    /// Option's value.
+    /// Return the only other argument (non-option).
     ///
-    /// The value is a variant of enum [`Option`]. Value `None` means
-    /// that there is no value for the option. Value `Some(String)`
-    /// provides a value.
-    pub value: Option<String>,
-}
+    /// This is useful for programs which accept exactly one non-option
+    /// argument, like a file name. The return value is `Ok(&str)` if
+    /// there was exactly one element in [`Args::other`] field. Otherwise
+    /// the return value is `Err` with a variant of enum
+    /// [`OtherArgsError`].
+    pub fn exactly_one_other(&self) -> Result<&str, OtherArgsError> {
+        match self.other.len() {
+            0 => Err(OtherArgsError::Missing),
+            1 => Ok(&self.other[0]),
+            n => Err(OtherArgsError::TooMany(n)),
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use alloc::vec;
+    /// Find option values which violate integer range.
+    ///
+    /// Options which were defined with [`OptSpecs::option_range`]
+    /// method must have an integer value within the given range. This
+    /// method finds options whose value is not an integer or whose
+    /// value is outside the range. Options without a value are not
+    /// included; use [`required_value_missing`](Args::required_value_missing)
+    /// method to find them. The `specs` argument should be the same
+    /// [`OptSpecs`] struct that was used for parsing.
+    ///
+    /// The return value is a vector of tuples (possibly empty). The
+    /// first element of the tuple is a reference to [`Opt`] struct in
+    /// the original [`Args::options`] field and the second element is
+    /// a short error message. Items are in the same order as in the
+    /// parsed command line.
+    pub fn range_violations(&self, specs: &OptSpecs) -> Vec<(&Opt, &'static str)> {
+        let mut result = Vec::new();
+
+        for opt in &self.options {
+            let range = match specs
+                .options
+                .iter()
+                .find(|s| s.name == opt.name && s.id == opt.id)
+                .and_then(|s| s.range)
+            {
+                Some(r) => r,
+                None => continue,
+            };
+            let value = match &opt.value {
+                Some(v) => v,
+                None => continue,
+            };
+            match value.parse::<i64>() {
+                Ok(n) if n < range.0 || n > range.1 => {
+                    result.push((opt, "Value is out of range."));
+                }
+                Ok(_) => (),
+                Err(_) => result.push((opt, "Value is not an integer.")),
+            }
+        }
+        result
+    }
 
-    #[test]
-    fn t_create_optspecs_010() {
-        let mut spec;
-        let mut expect;
+    /// Create [`Args`] struct from name and value pairs.
+    ///
+    /// The `map` argument is of any type that implements trait
+    /// [`IntoIterator`] and whose items are `(name, value)` tuples, for
+    /// example a `HashMap<String, String>` read from a configuration
+    /// file. Each `name` is option's name (without `-` or `--` prefix)
+    /// and it must match an option in the `specs` argument. The created
+    /// [`Opt`] structs get their identifiers and value types from
+    /// `specs`. Options are in the same order as the items in `map` and
+    /// their [`Opt::source_index`] field is the index of the item.
+    ///
+    /// The value is handled according to option's value type (see
+    /// [`OptValue`]). Empty string means no value for options which
+    /// don't accept a value. For value types
+    /// [`OptValue::OptionalNonEmpty`] and [`OptValue::RequiredNonEmpty`]
+    /// the empty string is not considered a value.
+    ///
+    /// The return value is `Ok(Args)` if all items could be converted.
+    /// Otherwise the return value is `Err` with a variant of enum
+    /// [`ConversionError`] for the first failed item. Fields
+    /// [`Args::other`] and [`Args::unknown`] will be empty.
+    pub fn from_map_with_specs<I, K, V>(map: I, specs: &OptSpecs) -> Result<Args, ConversionError>
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: ToString,
+        V: ToString,
+    {
+        let mut args = Args::new();
+
+        for (index, (name, value)) in map.into_iter().enumerate() {
+            let name = name.to_string();
+            let value = value.to_string();
+
+            let spec = match specs.options.iter().find(|s| s.name == name) {
+                Some(s) => s,
+                None => return Err(ConversionError::UnknownOption(name)),
+            };
+
+            let (value_required, value) = match spec.value_type {
+                OptValue::None if value.is_empty() => (false, None),
+                OptValue::None => return Err(ConversionError::ValueNotAccepted(name)),
+                OptValue::Optional => (false, Some(value)),
+                OptValue::OptionalNonEmpty => (false, Some(value).filter(|v| !v.is_empty())),
+                OptValue::Required => (true, Some(value)),
+                OptValue::RequiredNonEmpty => (true, Some(value).filter(|v| !v.is_empty())),
+            };
+
+            args.options.push(Opt {
+                id: spec.id.clone(),
+                name,
+                value_required,
+                value,
+                source_index: index,
+                no_prefix: spec.no_prefix,
+            });
+        }
+        Ok(args)
+    }
 
-        spec = OptSpecs::new().option("help", "help", OptValue::None);
-        expect = OptSpec {
-            id: String::from("help"),
-            name: String::from("help"),
-            value_type: OptValue::None,
-        };
-        assert_eq!(1, spec.options.len());
-        assert_eq!(&expect, &spec.options[0]);
-        assert_eq!(COUNTER_LIMIT, spec.option_limit);
-        assert_eq!(COUNTER_LIMIT, spec.other_limit);
-        assert_eq!(COUNTER_LIMIT, spec.unknown_limit);
+    /// Separate unknown options from the parsed output.
+    ///
+    /// This method consumes the [`Args`] struct and returns a tuple
+    /// `(Args, Vec<String>)`. The first element is the same struct but
+    /// its [`Args::unknown`] field is empty. The second element is the
+    /// original [`Args::unknown`] vector. This is useful when unknown
+    /// options are handed to some other part of the program.
+    pub fn partition_known_unknown(mut self) -> (Args, Vec<String>) {
+        let unknown = core::mem::take(&mut self.unknown);
+        (self, unknown)
+    }
 
-        spec = spec.option("file", "f", OptValue::Optional);
-        expect = OptSpec {
-            id: String::from("file"),
-            name: String::from("f"),
-            value_type: OptValue::Optional,
-        };
-        assert_eq!(2, spec.options.len());
-        assert_eq!(&expect, &spec.options[1]);
+    /// Find violated option dependencies.
+    ///
+    /// Option dependencies are defined with
+    /// [`OptSpecs::option_depends_on`] and
+    /// [`OptSpecs::option_requires_id`] methods. This method checks the
+    /// dependencies in `specs` argument, which should be the same
+    /// [`OptSpecs`] struct that was used for parsing.
+    ///
+    /// The return value is a vector (possibly empty) of
+    /// [`DependencyError`] structs, one for each violated dependency.
+    /// Dependencies defined with [`OptSpecs::option_depends_on`] come
+    /// first and then the ones defined with
+    /// [`OptSpecs::option_requires_id`], both in the same order as they
+    /// were defined.
+    pub fn check_dependencies(&self, specs: &OptSpecs) -> Vec<DependencyError> {
+        let by_id = specs
+            .dependencies
+            .iter()
+            .filter(|(dependent, required)| {
+                self.option_exists(dependent) && !self.option_exists(required)
+            })
+            .map(|(dependent, required)| DependencyError {
+                dependent: dependent.clone(),
+                dependent_name: None,
+                required: required.clone(),
+            });
+
+        let by_name = specs
+            .name_dependencies
+            .iter()
+            .filter(|(_, required)| !self.option_exists(required))
+            .filter_map(|(name, required)| {
+                let opt = self.options.iter().find(|opt| &opt.name == name)?;
+                Some(DependencyError {
+                    dependent: opt.id.clone(),
+                    dependent_name: Some(opt.display_name()),
+                    required: required.clone(),
+                })
+            });
+
+        by_id.chain(by_name).collect()
+    }
 
-        spec = spec.option("file", "file", OptValue::Required);
-        expect = OptSpec {
-            id: String::from("file"),
-            name: String::from("file"),
-            value_type: OptValue::Required,
-        };
-        assert_eq!(3, spec.options.len());
-        assert_eq!(&expect, &spec.options[2]);
+    /// Verify that mutually exclusive options were not given together.
+    ///
+    /// Groups of mutually exclusive options are defined with
+    /// [`OptSpecs::option_exclusive`] method. This method checks the
+    /// groups in `specs` argument, which should be the same [`OptSpecs`]
+    /// struct that was used for parsing.
+    ///
+    /// The return value is `Ok(())` if no group was violated. Otherwise
+    /// the return value is `Err` with [`ExclusiveGroupError`] struct for
+    /// the first violated group. The struct implements [`Display`](fmt::Display)
+    /// trait for a friendly error message.
+    pub fn verify_exclusive_groups(&self, specs: &OptSpecs) -> Result<(), ExclusiveGroupError> {
+        for group in &specs.exclusive_groups {
+            let mut ids: Vec<&str> = Vec::new();
+            let mut names: Vec<String> = Vec::new();
+
+            for opt in &self.options {
+                if group.contains(&opt.id) && !ids.contains(&opt.id.as_str()) {
+                    ids.push(&opt.id);
+                    names.push(opt.display_name());
+                }
+            }
 
-        spec = spec.flag(OptFlags::OptionsEverywhere);
-        assert_eq!(1, spec.flags.len()); // Length 1
-        assert_eq!(true, spec.is_flag(OptFlags::OptionsEverywhere));
-        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
-        assert_eq!(2, spec.flags.len()); // Length 2
-        assert_eq!(true, spec.is_flag(OptFlags::PrefixMatchLongOptions));
-        spec = spec.flag(OptFlags::OptionsEverywhere);
-        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
-        assert_eq!(2, spec.flags.len()); // Length still 2
+            if ids.len() > 1 {
+                return Err(ExclusiveGroupError {
+                    ids: ids.iter().map(|s| s.to_string()).collect(),
+                    names,
+                });
+            }
+        }
+        Ok(())
+    }
 
-        spec = spec.limit_options(9);
-        spec = spec.limit_other_args(10);
-        spec = spec.limit_unknown_options(3);
-        assert_eq!(9, spec.option_limit);
-        assert_eq!(10, spec.other_limit);
-        assert_eq!(3, spec.unknown_limit);
+    /// Convert options to identifier and value pairs.
+    ///
+    /// This method consumes the [`Args`] struct and returns a vector of
+    /// `(id, value)` tuples for options which have a value. Options
+    /// without a value are skipped. Items are in the same order as in
+    /// the parsed command line.
+    pub fn into_pairs(self) -> Vec<(String, String)> {
+        self.options
+            .into_iter()
+            .filter_map(|opt| opt.value.map(|v| (opt.id, v)))
+            .collect()
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_020() {
-        OptSpecs::new().option("", "h", OptValue::None);
+    /// Find option's value or environment variable's value.
+    ///
+    /// This method first finds the last value for the given option `id`
+    /// (see [`options_value_last`](Args::options_value_last)). If there
+    /// is no value the method reads environment variable `env_var`
+    /// instead. The return value is `Some(String)` from whichever
+    /// source provides a value first, or `None` if neither does.
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn option_env_value(&self, id: &str, env_var: &str) -> Option<String> {
+        match self.options_value_last(id) {
+            Some(v) => Some(v.clone()),
+            None => std::env::var(env_var).ok(),
+        }
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_030() {
-        OptSpecs::new()
-            .option("h", "h", OptValue::None)
-            .option("h", "h", OptValue::None);
+    /// Find the last value for given option `id` as a path.
+    ///
+    /// This is the same as
+    /// [`options_value_last`](Args::options_value_last) method but the
+    /// value is converted to [`PathBuf`](std::path::PathBuf).
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn option_value_to_path(&self, id: &str) -> Option<std::path::PathBuf> {
+        self.options_value_last(id).map(std::path::PathBuf::from)
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_040() {
-        OptSpecs::new().option("h", "", OptValue::None);
+    /// Return all distinct option identifiers in alphabetical order.
+    ///
+    /// The return value is a vector of option identifiers (see
+    /// [`Opt::id`]) in [`Args::options`] field. Each identifier is in
+    /// the vector only once even if the option was given several times
+    /// in the command line. The vector is sorted and it is empty if
+    /// there were no options.
+    pub fn all_option_ids(&self) -> Vec<&str> {
+        let mut ids: Vec<&str> = self.options.iter().map(|opt| opt.id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        ids
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_050() {
-        OptSpecs::new().option("h", "-", OptValue::None);
+    /// Parse the last value for given option `id` or return a default.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and parse it
+    /// to type `T` with [`FromStr`] trait's method `from_str`. If there
+    /// is no value or parsing fails the return value is `default`.
+    pub fn option_value_parse_or<T: FromStr>(&self, id: &str, default: T) -> T {
+        self.options_value_last(id)
+            .and_then(|v| v.parse::<T>().ok())
+            .unwrap_or(default)
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_060() {
-        OptSpecs::new().option("h", " ", OptValue::None);
+    /// Print program's version if it was requested.
+    ///
+    /// If the command line had option with identifier `version` and
+    /// version string was stored with [`OptSpecs::with_version_info`]
+    /// method, print the version string to the standard output and
+    /// return `true`. Otherwise return `false`. The `specs` argument
+    /// should be the same [`OptSpecs`] struct that was used for
+    /// parsing.
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn print_version_if_requested(&self, specs: &OptSpecs) -> bool {
+        match &specs.version {
+            Some(v) if self.option_exists("version") => {
+                std::println!("{}", v);
+                true
+            }
+            _ => false,
+        }
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_070() {
-        OptSpecs::new().option("h", "hh ", OptValue::None);
+    /// Find the last value for given option `id` without whitespace.
+    ///
+    /// This is the same as
+    /// [`options_value_last`](Args::options_value_last) method but
+    /// leading and trailing whitespace is removed from the value.
+    pub fn option_value_trimmed<'a>(&'a self, id: &str) -> Option<&'a str> {
+        self.options_value_last(id).map(|v| v.trim())
     }
 
-    #[test]
-    #[should_panic]
-    fn t_create_optspecs_080() {
-        OptSpecs::new().option("h", "hh=hh", OptValue::None);
+    /// Return boolean whether other arguments contain the given string.
+    ///
+    /// Check if string `s` is one of the other arguments (non-options)
+    /// in [`Args::other`] field.
+    pub fn other_contains(&self, s: &str) -> bool {
+        self.other.iter().any(|o| o == s)
     }
 
-    #[test]
-    fn t_is_flag() {
-        let mut spec = OptSpecs::new().flag(OptFlags::OptionsEverywhere);
-        assert_eq!(true, spec.is_flag(OptFlags::OptionsEverywhere));
+    /// Return boolean whether other arguments contain any of the strings.
+    ///
+    /// This is similar to [`other_contains`](Args::other_contains) method
+    /// but this method checks several strings at once. The return value
+    /// is `true` if at least one string in `strs` is in [`Args::other`]
+    /// field.
+    pub fn other_contains_any(&self, strs: &[&str]) -> bool {
+        strs.iter().any(|s| self.other_contains(s))
+    }
 
-        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
-        assert_eq!(true, spec.is_flag(OptFlags::PrefixMatchLongOptions));
+    /// Replace option names with canonical long names.
+    ///
+    /// This method consumes the [`Args`] struct and returns it with
+    /// modified [`Opt::name`] fields. Each option's name is replaced
+    /// with the first long option name that has the same identifier in
+    /// `specs` argument, which should be the same [`OptSpecs`] struct
+    /// that was used for parsing. For example, if user gave `-f` and
+    /// option `f` has the same identifier as option `file` then the
+    /// name becomes `file`. Names are not changed if there is no long
+    /// option with the same identifier.
+    ///
+    /// This is useful for producing normalized output, like
+    /// configuration files.
+    pub fn normalize_names(mut self, specs: &OptSpecs) -> Self {
+        for opt in &mut self.options {
+            if let Some(spec) = specs
+                .options
+                .iter()
+                .find(|s| s.id == opt.id && !s.no_prefix && s.name.chars().count() > 1)
+            {
+                opt.name = spec.name.clone();
+            }
+        }
+        self
     }
 
-    #[test]
-    fn t_parsed_output_010() {
-        let parsed = OptSpecs::new()
-            .option("help", "h", OptValue::None)
-            .option("help", "help", OptValue::None)
-            .option("file", "f", OptValue::Required)
-            .option("file", "file", OptValue::Required)
-            .getopt(["-h", "--help", "-f123", "-f", "456", "foo", "bar"]);
+    /// Find options with the given `id` which are defined in `specs`.
+    ///
+    /// This is similar to [`options_all`](Args::options_all) method but
+    /// this method only includes options whose name and identifier
+    /// match an option specification in `specs` argument. Options that
+    /// don't come from parsing with `specs`, like options added by
+    /// program's code or created with
+    /// [`from_map_with_specs`](Args::from_map_with_specs) using other
+    /// specification, are excluded.
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty, if no matches) and each item is a reference to
+    /// [`Opt`] struct in the original [`Args::options`] field. Items
+    /// are in the same order as in the parsed command line.
+    pub fn option_defined_in_specs<'a>(
+        &'a self,
+        id: &str,
+        specs: &'a OptSpecs,
+    ) -> impl DoubleEndedIterator<Item = &'a Opt> {
+        let id = id.to_string();
+        self.options.iter().filter(move |opt| {
+            opt.id == id
+                && specs
+                    .options
+                    .iter()
+                    .any(|s| s.name == opt.name && s.id == opt.id)
+        })
+    }
 
-        assert_eq!(true, parsed.option_exists("help"));
-        assert_eq!(true, parsed.option_exists("file"));
-        assert_eq!(false, parsed.option_exists("x"));
+    /// Write the parsed output in human-readable form.
+    ///
+    /// This is meant for program development and tests. The output is
+    /// written to `w` which implements [`Write`](std::io::Write) trait,
+    /// for example [`std::io::stderr`]. Each option, other argument
+    /// and unknown option is written on its own numbered line and the
+    /// type of the item is marked with a symbol: `[O]` for option,
+    /// `[+]` for other argument and `[U]` for unknown option. For
+    /// example:
+    ///
+    /// ```text
+    /// 1 [O] --file (file) = "123"
+    /// 2 [O] -v (verbose)
+    /// 3 [+] "foo"
+    /// 4 [U] -a
+    /// ```
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn debug_print_to<W: std::io::Write>(&self, w: &mut W) -> std::io::Result<()> {
+        let mut n = 0;
+
+        for opt in &self.options {
+            n += 1;
+            write!(w, "{} [O] {} ({})", n, opt.display_name(), opt.id)?;
+            match &opt.value {
+                Some(v) => writeln!(w, " = {:?}", v)?,
+                None => writeln!(w)?,
+            }
+        }
 
-        assert_eq!("h", parsed.options_first("help").unwrap().name);
-        assert_eq!("help", parsed.options_last("help").unwrap().name);
-        assert_eq!("help", parsed.options_first("help").unwrap().id);
-        assert_eq!("help", parsed.options_last("help").unwrap().id);
-        assert_eq!(false, parsed.options_first("help").unwrap().value_required);
-        assert_eq!(false, parsed.options_last("help").unwrap().value_required);
+        for o in &self.other {
+            n += 1;
+            writeln!(w, "{} [+] {:?}", n, o)?;
+        }
 
-        assert_eq!("f", parsed.options_first("file").unwrap().name);
-        assert_eq!(
-            "123",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
-        );
-        assert_eq!(
-            "456",
+        for u in &self.unknown {
+            n += 1;
+            writeln!(w, "{} [U] {}", n, prefixed_name(u))?;
+        }
+        Ok(())
+    }
+
+    /// Return the parsed output as owned data.
+    ///
+    /// [`Args`] struct already owns all its data so this method just
+    /// returns the same struct. The method exists so that programs can
+    /// explicitly require owned output, which will keep working if the
+    /// struct ever borrows data from command-line arguments.
+    pub fn into_owned(self) -> Args {
+        self
+    }
+
+    /// Interpret the last value for given option `id` as a boolean.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and interpret
+    /// it as a boolean. Values `true`, `1`, `yes` and `on` mean `true`
+    /// and values `false`, `0`, `no` and `off` mean `false`. The
+    /// comparison is case-insensitive.
+    ///
+    /// The return value is `None` if there is no value for the option.
+    /// Otherwise it is `Some(Ok(bool))` for a recognized value or
+    /// `Some(Err(value))` for an unrecognized value.
+    pub fn option_value_as_bool<'a>(&'a self, id: &str) -> Option<Result<bool, &'a str>> {
+        let value = self.options_value_last(id)?;
+        let matches = |words: &[&str]| words.iter().any(|w| w.eq_ignore_ascii_case(value));
+
+        if matches(&["true", "1", "yes", "on"]) {
+            Some(Ok(true))
+        } else if matches(&["false", "0", "no", "off"]) {
+            Some(Ok(false))
+        } else {
+            Some(Err(value))
+        }
+    }
+
+    /// Split the last value for given option `id` by commas.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and split it
+    /// to parts separated by comma characters. For example, command
+    /// line `--include=foo,bar,baz` gives `foo`, `bar` and `baz`. The
+    /// return value is `None` if there is no value for the option.
+    /// Also see method [`option_value_split`](Args::option_value_split).
+    pub fn option_value_as_list<'a>(&'a self, id: &str) -> Option<Vec<&'a str>> {
+        self.option_value_split(id, ',')
+    }
+
+    /// Split the last value for given option `id` by separator `sep`.
+    ///
+    /// This is the same as
+    /// [`option_value_as_list`](Args::option_value_as_list) method but
+    /// the separator character is given as an argument.
+    pub fn option_value_split<'a>(&'a self, id: &str, sep: char) -> Option<Vec<&'a str>> {
+        self.options_value_last(id).map(|v| v.split(sep).collect())
+    }
+
+    /// Return the distinct option names given in the command line.
+    ///
+    /// Collect the [`Opt::name`] fields of all options without
+    /// duplicates. The names are in the order of their first occurrence
+    /// in the command line. Unlike [`all_option_ids`](Args::all_option_ids)
+    /// method this returns the names as the user wrote them, like `f` or
+    /// `file`, not the identifiers.
+    pub fn unique_option_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for opt in &self.options {
+            if !names.contains(&opt.name.as_str()) {
+                names.push(&opt.name);
+            }
+        }
+        names
+    }
+
+    /// Append strings to other arguments and return the struct.
+    ///
+    /// This method consumes the [`Args`] struct, appends the strings of
+    /// `extra` to the end of [`Args::other`] field and returns the
+    /// modified struct. This suits chained method calls. Also see method
+    /// [`extend_other`](Args::extend_other).
+    pub fn with_extra_other<I, S>(mut self, extra: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.extend_other(extra);
+        self
+    }
+
+    /// Append strings to other arguments.
+    ///
+    /// This is the same as [`with_extra_other`](Args::with_extra_other)
+    /// method but this modifies the struct through a mutable reference.
+    pub fn extend_other<I, S>(&mut self, extra: I)
+    where
+        I: IntoIterator<Item = S>,
+        S: ToString,
+    {
+        self.other.extend(extra.into_iter().map(|s| s.to_string()));
+    }
+
+    /// Check that there is at most one other argument (non-option).
+    ///
+    /// The return value is `Ok(())` if [`Args::other`] field has zero
+    /// or one element. Otherwise the return value is `Err` with
+    /// [`ExtraArgsError`] struct whose `expected` field is 1. Also see
+    /// methods [`check_exactly_n_other`](Args::check_exactly_n_other)
+    /// and [`check_at_least_n_other`](Args::check_at_least_n_other).
+    pub fn check_at_most_one_other(&self) -> Result<(), ExtraArgsError> {
+        if self.other.len() > 1 {
+            Err(self.extra_args_error(1))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check that there are exactly `n` other arguments (non-options).
+    ///
+    /// The return value is `Ok(())` if [`Args::other`] field has
+    /// exactly `n` elements. Otherwise the return value is `Err` with
+    /// [`ExtraArgsError`] struct whose `expected` field is `n`.
+    pub fn check_exactly_n_other(&self, n: usize) -> Result<(), ExtraArgsError> {
+        if self.other.len() != n {
+            Err(self.extra_args_error(n))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Check that there are at least `n` other arguments (non-options).
+    ///
+    /// The return value is `Ok(())` if [`Args::other`] field has `n`
+    /// or more elements. Otherwise the return value is `Err` with
+    /// [`ExtraArgsError`] struct whose `expected` field is `n`.
+    pub fn check_at_least_n_other(&self, n: usize) -> Result<(), ExtraArgsError> {
+        if self.other.len() < n {
+            Err(self.extra_args_error(n))
+        } else {
+            Ok(())
+        }
+    }
+
+    fn extra_args_error(&self, expected: usize) -> ExtraArgsError {
+        ExtraArgsError {
+            expected,
+            found: self.other.len(),
+            extra: self.other.iter().skip(expected).cloned().collect(),
+        }
+    }
+
+    /// Parse the last value for given option `id` as an integer in `base`.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and parse it
+    /// as an integer in the number base `base` with [`i64`] type's
+    /// function `from_str_radix`. The value may start with `+` or `-`
+    /// sign. Common prefixes `0x` (base 16), `0o` (base 8) and `0b`
+    /// (base 2) are accepted and removed before parsing, so
+    /// `--address=0x1F00` and `--address=1F00` give the same result.
+    /// A prefix is removed only if it matches `base`. The sign must be
+    /// before the prefix and there can be only one sign, so values like
+    /// `+-5` and `0x-1F` are errors.
+    ///
+    /// The return value is `None` if there is no value for the option.
+    /// Otherwise it is the result of parsing.
+    ///
+    /// This method panics if `base` is not in the range from 2 to 36.
+    pub fn option_value_base(&self, id: &str, base: u32) -> Option<Result<i64, ParseIntError>> {
+        let value = self.options_value_last(id)?;
+        let (sign, digits) = match value.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", value.strip_prefix('+').unwrap_or(value)),
+        };
+
+        let prefix = match base {
+            16 => "0x",
+            8 => "0o",
+            2 => "0b",
+            _ => "",
+        };
+        let digits = match digits.get(..prefix.len()) {
+            Some(p) if !prefix.is_empty() && p.eq_ignore_ascii_case(prefix) => {
+                &digits[prefix.len()..]
+            }
+            _ => digits,
+        };
+
+        if digits.starts_with(|c| c == '+' || c == '-') {
+            // The second sign is an invalid digit. Parse it after a
+            // valid digit to get the error.
+            return Some(i64::from_str_radix(&alloc::format!("0{}", digits), base));
+        }
+
+        Some(i64::from_str_radix(
+            &alloc::format!("{}{}", sign, digits),
+            base,
+        ))
+    }
+
+    /// Decode the last value for given option `id` from base64.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and decode it
+    /// as base64 data with the standard alphabet. This is for passing
+    /// binary data in the command line, like `--key=3q2+7w==`. The `=`
+    /// padding characters at the end are optional.
+    ///
+    /// The return value is `None` if there is no value for the option.
+    /// Otherwise it is `Some(Ok(bytes))` or `Some(Err(error))` if the
+    /// value is not valid base64 data.
+    ///
+    /// This method is available only with the crate feature `base64`.
+    #[cfg(feature = "base64")]
+    pub fn option_value_bytes(&self, id: &str) -> Option<Result<Vec<u8>, Base64DecodeError>> {
+        self.options_value_last(id).map(|v| base64::decode(v))
+    }
+
+    /// Return unknown long option names in sorted order.
+    ///
+    /// Collect the long option names (two or more characters) from
+    /// [`Args::unknown`] field and sort them. The field doesn't have
+    /// duplicates so the names are unique. This is useful for printing
+    /// error messages in a predictable order. Also see method
+    /// [`unique_unknown_short`](Args::unique_unknown_short).
+    pub fn unique_unknown_long(&self) -> Vec<&str> {
+        self.sorted_unknown(|n| n > 1)
+    }
+
+    /// Return unknown short option names in sorted order.
+    ///
+    /// This is the same as
+    /// [`unique_unknown_long`](Args::unique_unknown_long) method but
+    /// this method returns the short option names (single character).
+    pub fn unique_unknown_short(&self) -> Vec<&str> {
+        self.sorted_unknown(|n| n == 1)
+    }
+
+    fn sorted_unknown<F: Fn(usize) -> bool>(&self, count: F) -> Vec<&str> {
+        let mut names: Vec<&str> = self
+            .unknown
+            .iter()
+            .map(|u| u.as_str())
+            .filter(|u| count(u.chars().count()))
+            .collect();
+        names.sort_unstable();
+        names.dedup();
+        names
+    }
+
+    /// Validate the parsed output with a custom function.
+    ///
+    /// This method consumes the [`Args`] struct and calls `validator`
+    /// function with a reference to it. The function returns a vector
+    /// of error messages. If the vector is empty the return value is
+    /// `Ok(Args)` with the same struct. Otherwise the return value is
+    /// `Err` with the messages.
+    ///
+    /// Several validations can be done one after another:
+    ///
+    /// ```
+    /// # use just_getopt::{Args, OptSpecs, OptValue};
+    /// # fn main() -> Result<(), Vec<String>> {
+    /// fn check_file(args: &Args) -> Vec<String> {
+    ///     match args.options_value_last("file") {
+    ///         Some(_) => Vec::new(),
+    ///         None => vec!["Option --file is required.".to_string()],
+    ///     }
+    /// }
+    ///
+    /// let parsed = OptSpecs::new()
+    ///     .option("file", "file", OptValue::Required)
+    ///     .getopt(["--file=foo.txt", "bar"])
+    ///     .validate_with(check_file)?
+    ///     .validate_with(|args| match args.other.len() {
+    ///         1 => Vec::new(),
+    ///         _ => vec!["Expected one argument.".to_string()],
+    ///     })?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn validate_with<F>(self, validator: F) -> Result<Args, Vec<String>>
+    where
+        F: FnOnce(&Args) -> Vec<String>,
+    {
+        let messages = validator(&self);
+        if messages.is_empty() {
+            Ok(self)
+        } else {
+            Err(messages)
+        }
+    }
+
+    /// Find exactly `n` options with the given `id`.
+    ///
+    /// This is useful for options which must be given exactly `n` times
+    /// in the command line. The return value is `Ok` with a vector of
+    /// references to [`Opt`] structs in the original [`Args::options`]
+    /// field if there were exactly `n` options with identifier `id`.
+    /// Items are in the same order as in the parsed command line.
+    /// Otherwise the return value is `Err` with [`ArityError`] struct.
+    pub fn options_n(&self, id: &str, n: usize) -> Result<Vec<&Opt>, ArityError> {
+        let options: Vec<&Opt> = self.options.iter().filter(|opt| opt.id == id).collect();
+        if options.len() == n {
+            Ok(options)
+        } else {
+            Err(ArityError {
+                id: id.to_string(),
+                expected: n,
+                found: options.len(),
+            })
+        }
+    }
+
+    /// Count options with the given `id`.
+    ///
+    /// Return the number of options with identifier `id` in
+    /// [`Args::options`] field. This is the same as counting the items
+    /// of [`options_all`](Args::options_all) method. Options which
+    /// were ignored because of
+    /// [`OptSpecs::limit_options`] are not counted. This is useful
+    /// for repeated options like `-v -v -v` which increase verbosity.
+    pub fn option_count_id(&self, id: &str) -> usize {
+        self.options.iter().filter(|opt| opt.id == id).count()
+    }
+
+    /// Count option values for the given `id`.
+    ///
+    /// Return the number of options with identifier `id` in
+    /// [`Args::options`] field which have a value. This is the same as
+    /// counting the items of [`options_value_all`](Args::options_value_all)
+    /// method. Also see method [`option_count_id`](Args::option_count_id)
+    /// which counts options with or without a value.
+    pub fn options_value_count(&self, id: &str) -> usize {
+        self.options_value_all(id).count()
+    }
+
+    /// Count option values for all identifiers.
+    ///
+    /// The return value is a [`BTreeMap`] whose keys are the
+    /// identifiers in [`Args::options`] field and values are the
+    /// numbers of options with a value (see
+    /// [`options_value_count`](Args::options_value_count)). Identifiers
+    /// whose options don't have any values are included with count 0.
+    pub fn options_value_count_all(&self) -> BTreeMap<String, usize> {
+        let mut map = BTreeMap::new();
+        for opt in &self.options {
+            let count = map.entry(opt.id.clone()).or_insert(0);
+            if opt.value.is_some() {
+                *count += 1;
+            }
+        }
+        map
+    }
+
+    /// Write the parsed options and other arguments as TOML data.
+    ///
+    /// Each option identifier in [`Args::options`] field becomes a key
+    /// in table `[options]`, in the order of first occurrence. The
+    /// key's value is an array of the option's values. Options without
+    /// a value add nothing to the array, so it can be empty. Other
+    /// arguments (non-options) are written as array `args` in table
+    /// `[other]`. Unknown options are not included. For example:
+    ///
+    /// ```text
+    /// [options]
+    /// file = ["a.txt", "b.txt"]
+    /// verbose = []
+    ///
+    /// [other]
+    /// args = ["foo"]
+    /// ```
+    ///
+    /// Both tables are always written, even if they are empty. This is
+    /// useful for saving command-line settings to a configuration file.
+    ///
+    /// This method is available only with the crate feature `toml`.
+    #[cfg(feature = "toml")]
+    pub fn to_structured_toml(&self) -> String {
+        let mut result = String::from("[options]\n");
+        let mut seen: Vec<&str> = Vec::new();
+
+        for opt in &self.options {
+            if seen.contains(&opt.id.as_str()) {
+                continue;
+            }
+            seen.push(&opt.id);
+
+            if opt
+                .id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-')
+            {
+                result.push_str(&opt.id);
+            } else {
+                result.push_str(&toml_string(&opt.id));
+            }
+            result.push_str(" = ");
+            result.push_str(&toml_array(
+                self.options
+                    .iter()
+                    .filter(|o| o.id == opt.id)
+                    .filter_map(|o| o.value.as_deref()),
+            ));
+            result.push('\n');
+        }
+
+        result.push_str("\n[other]\nargs = ");
+        result.push_str(&toml_array(self.other.iter().map(|s| s.as_str())));
+        result.push('\n');
+        result
+    }
+
+    /// Find unknown long options.
+    ///
+    /// Return the long option names (two or more characters) from
+    /// [`Args::unknown`] field. The return value implements the
+    /// [`DoubleEndedIterator`] trait (possibly empty) and the items are
+    /// in the same order as in the field. Also see method
+    /// [`unknown_short`](Args::unknown_short).
+    pub fn unknown_long(&self) -> impl DoubleEndedIterator<Item = &String> {
+        self.unknown.iter().filter(|u| u.chars().count() > 1)
+    }
+
+    /// Find unknown short options.
+    ///
+    /// This is the same as [`unknown_long`](Args::unknown_long) method
+    /// but this method returns the short option names (single
+    /// character).
+    pub fn unknown_short(&self) -> impl DoubleEndedIterator<Item = &String> {
+        self.unknown.iter().filter(|u| u.chars().count() == 1)
+    }
+
+    /// Pair options with the given `id` with other arguments.
+    ///
+    /// The first option with identifier `id` is paired with the first
+    /// other argument (non-option) in [`Args::other`] field, the second
+    /// with the second and so on. This is useful for command lines like
+    /// `-t type1 file1 -t type2 file2` (with
+    /// [`OptFlags::OptionsEverywhere`] flag). If there are fewer other
+    /// arguments than options the rest of the options are paired with
+    /// `None`. Extra other arguments are ignored.
+    ///
+    /// The return value implements the [`Iterator`] trait (possibly
+    /// empty, if no matches) and each item is a tuple of a reference to
+    /// [`Opt`] struct in the original [`Args::options`] field and an
+    /// optional other argument. Items are in the same order as in the
+    /// parsed command line.
+    pub fn options_zip_other(&self, id: &str) -> impl Iterator<Item = (&Opt, Option<&str>)> {
+        let id = id.to_string();
+        let mut other = self.other.iter();
+        self.options
+            .iter()
+            .filter(move |opt| opt.id == id)
+            .map(move |opt| (opt, other.next().map(|s| s.as_str())))
+    }
+
+    /// Return boolean whether nothing is left unhandled.
+    ///
+    /// The return value is `true` if [`Args::other`] and
+    /// [`Args::unknown`] fields are empty and no option is missing its
+    /// required value (see
+    /// [`required_value_missing`](Args::required_value_missing)). This
+    /// is useful as a final check after the program has removed the
+    /// other arguments it expects from [`Args::other`] field.
+    pub fn is_fully_parsed(&self) -> bool {
+        self.other.is_empty()
+            && self.unknown.is_empty()
+            && self.required_value_missing().next().is_none()
+    }
+
+    /// Return boolean whether the parsed output is empty.
+    ///
+    /// The return value is `true` if [`Args::options`], [`Args::other`]
+    /// and [`Args::unknown`] fields are all empty. This usually means
+    /// that the command line had no arguments. Also see method
+    /// [`len`](Args::len).
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Return the total number of parsed items.
+    ///
+    /// The return value is the sum of the lengths of [`Args::options`],
+    /// [`Args::other`] and [`Args::unknown`] fields.
+    pub fn len(&self) -> usize {
+        self.options.len() + self.other.len() + self.unknown.len()
+    }
+
+    /// Group options by their identifiers.
+    ///
+    /// The return value is a [`BTreeMap`] whose keys are the
+    /// identifiers in [`Args::options`] field, in sorted order. Each
+    /// value is a vector of references to [`Opt`] structs with that
+    /// identifier, in the same order as in the parsed command line.
+    /// This is useful for handling all options at once instead of
+    /// calling [`options_all`](Args::options_all) method for each
+    /// identifier.
+    pub fn group_by_id(&self) -> BTreeMap<&str, Vec<&Opt>> {
+        let mut map = BTreeMap::new();
+        for opt in &self.options {
+            map.entry(opt.id.as_str())
+                .or_insert_with(Vec::new)
+                .push(opt);
+        }
+        map
+    }
+
+    /// Return option names and values as pairs.
+    ///
+    /// The return value is a vector of `(name, value)` tuples, one for
+    /// each option in [`Args::options`] field and in the same order.
+    /// The `name` is option's name in the command line (see
+    /// [`Opt::name`]) and the `value` is option's value or an empty
+    /// string if there is no value. This is a simple view for logging
+    /// and similar purposes. Note that an empty value and no value
+    /// can't be distinguished.
+    pub fn option_value_pairs(&self) -> Vec<(&str, &str)> {
+        self.options
+            .iter()
+            .map(|opt| (opt.name.as_str(), opt.value.as_deref().unwrap_or("")))
+            .collect()
+    }
+
+    /// Find the distinct option identifiers.
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty) and it yields each identifier in
+    /// [`Args::options`] field once, in the order of their first
+    /// occurrence in the parsed command line. This pairs well with
+    /// [`options_all`](Args::options_all) method for handling one
+    /// identifier at a time. Also see method
+    /// [`all_option_ids`](Args::all_option_ids) which returns the
+    /// identifiers in sorted order.
+    pub fn unique_ids(&self) -> impl DoubleEndedIterator<Item = &str> {
+        self.options
+            .iter()
+            .enumerate()
+            .filter(move |(i, opt)| !self.options[..*i].iter().any(|o| o.id == opt.id))
+            .map(|(_, opt)| opt.id.as_str())
+    }
+
+    /// Return all option values.
+    ///
+    /// The return value is a vector of the values of all options in
+    /// [`Args::options`] field, in the same order as in the parsed
+    /// command line. Options without a value are skipped. This is
+    /// useful for logging or for validating all values at once.
+    pub fn all_values_flat(&self) -> Vec<&str> {
+        self.options
+            .iter()
+            .filter_map(|opt| opt.value.as_deref())
+            .collect()
+    }
+
+    /// Find all options with the given `name`.
+    ///
+    /// This is similar to [`options_all`](Args::options_all) method but
+    /// options are matched by their name in the command line (see
+    /// [`Opt::name`]), not by their identifier. For example, if both
+    /// `-f` and `--file` have identifier `file` they can be found
+    /// separately with names `f` and `file`.
+    ///
+    /// The return value implements the [`DoubleEndedIterator`] trait
+    /// (possibly empty, if no matches) and each item is a reference to
+    /// [`Opt`] struct in the original [`Args::options`] field. Items
+    /// are in the same order as in the parsed command line.
+    pub fn options_by_name(&self, name: &str) -> impl DoubleEndedIterator<Item = &Opt> {
+        let name = name.to_string();
+        self.options.iter().filter(move |opt| opt.name == name)
+    }
+
+    /// Panic if option with the given `id` is not found.
+    ///
+    /// This is a helper for test code. The method panics with a
+    /// message like `Expected option with id 'file' but it was not
+    /// found.` if [`option_exists`](Args::option_exists) method returns
+    /// `false` for `id`.
+    #[track_caller]
+    pub fn assert_has_option(&self, id: &str) {
+        if !self.option_exists(id) {
+            panic!("Expected option with id '{}' but it was not found.", id);
+        }
+    }
+
+    /// Panic if the last value for option `id` is not `expected`.
+    ///
+    /// This is a helper for test code. The method finds the last value
+    /// for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and panics if
+    /// there is no value or if the value is not the same as `expected`.
+    #[track_caller]
+    pub fn assert_option_value(&self, id: &str, expected: &str) {
+        match self.options_value_last(id) {
+            Some(v) if v == expected => (),
+            Some(v) => panic!(
+                "Expected value '{}' for option with id '{}' but found '{}'.",
+                expected, id, v
+            ),
+            None => panic!(
+                "Expected value '{}' for option with id '{}' but there was no value.",
+                expected, id
+            ),
+        }
+    }
+
+    /// Return a copy with only the valid options.
+    ///
+    /// The returned [`Args`] struct has a copy of [`Args::options`]
+    /// field but [`Args::other`] and [`Args::unknown`] fields are
+    /// empty. This is useful for passing parsed options to a function
+    /// which should not see the other arguments or unknown options.
+    pub fn clone_options_only(&self) -> Args {
+        Args {
+            options: self.options.clone(),
+            ..Args::new()
+        }
+    }
+
+    /// Parse the last value for given option `id` as a duration.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and parse it
+    /// as a duration. The value is a non-negative integer followed by
+    /// an optional unit: `s` (seconds), `m` (minutes), `h` (hours) or
+    /// `d` (days). Without a unit the number means seconds. For
+    /// example, `--timeout=10s` and `--interval=5m`.
+    ///
+    /// The return value is `None` if there is no value for the option.
+    /// Otherwise it is `Some(Ok(Duration))` or `Some(Err(error))` with
+    /// [`ParseDurationError`] struct.
+    pub fn option_value_duration(
+        &self,
+        id: &str,
+    ) -> Option<Result<core::time::Duration, ParseDurationError>> {
+        self.options_value_last(id)
+            .map(|v| value::parse_duration(v))
+    }
+
+    /// Parse the last value for given option `id` as a size in bytes.
+    ///
+    /// Find the last value for option `id` (see
+    /// [`options_value_last`](Args::options_value_last)) and parse it
+    /// as a byte size. The value is a non-negative integer followed by
+    /// an optional unit. SI units `KB`, `MB`, `GB`, `TB`, `PB` and `EB`
+    /// are powers of 1000 and binary units `KiB`, `MiB`, `GiB`, `TiB`,
+    /// `PiB` and `EiB` are powers of 1024. Without a unit, or with unit
+    /// `B`, the number means bytes. For example, `--max-size=1GB` and
+    /// `--buffer-size=512KiB`.
+    ///
+    /// The return value is `None` if there is no value for the option.
+    /// Otherwise it is `Some(Ok(u64))` or `Some(Err(error))` with
+    /// [`ParseByteSizeError`] struct.
+    pub fn option_value_byte_size(&self, id: &str) -> Option<Result<u64, ParseByteSizeError>> {
+        self.options_value_last(id)
+            .map(|v| value::parse_byte_size(v))
+    }
+
+    /// Find the first option which has a value.
+    ///
+    /// Return a reference to the first [`Opt`] struct in
+    /// [`Args::options`] field whose `value` field is `Some`, or `None`
+    /// if no option has a value. Options without a value, like flags
+    /// `-v` or `--help`, are skipped. This is useful for programs which
+    /// treat the first option with a value as the primary input.
+    pub fn first_non_flag_option(&self) -> Option<&Opt> {
+        self.options.iter().find(|opt| opt.value.is_some())
+    }
+
+    /// Check the parsed command line for errors.
+    ///
+    /// Return a vector of all problems as [`ParseError`] enums: unknown
+    /// options in [`Args::unknown`] field first, then options with
+    /// missing required value (see
+    /// [`required_value_missing`](Args::required_value_missing)
+    /// method). An empty vector means that there were no errors.
+    pub fn validate(&self) -> Vec<ParseError> {
+        self.unknown
+            .iter()
+            .map(|u| ParseError::UnknownOption {
+                name: prefixed_name(u),
+            })
+            .chain(
+                self.required_value_missing()
+                    .map(|o| ParseError::RequiredValueMissing {
+                        option_name: o.display_name(),
+                    }),
+            )
+            .collect()
+    }
+
+    /// Print errors and a usage line if the command line had errors.
+    ///
+    /// If [`validate`](Args::validate) method finds errors (unknown
+    /// options or options with missing required value), print the
+    /// error messages and a usage line to the standard error output and
+    /// return `true`. Otherwise print nothing and return `false`. The
+    /// `usage` argument is a brief usage line without program's name,
+    /// like `"[OPTIONS] <file>"`. For example:
+    ///
+    /// ```text
+    /// prog: Unknown option '-x'.
+    /// Usage: prog [OPTIONS] <file>
+    /// ```
+    ///
+    /// This method is available only with the crate feature `std`.
+    #[cfg(feature = "std")]
+    pub fn print_compact_usage(&self, program_name: &str, usage: &str) -> bool {
+        let errors = self.validate();
+        if errors.is_empty() {
+            return false;
+        }
+
+        for e in &errors {
+            std::eprintln!("{}: {}", program_name, e);
+        }
+        std::eprintln!("Usage: {} {}", program_name, usage);
+        true
+    }
+
+    /// Return options between the first options of two identifiers.
+    ///
+    /// Find the first option with identifier `start_id` and the first
+    /// option with identifier `end_id` in [`Args::options`] field. The
+    /// return value is a slice of the options strictly between them,
+    /// in the same order as in the parsed command line. The slice is
+    /// empty if either option is missing or if `end_id` comes before
+    /// `start_id`. This is useful for section-based command lines like
+    /// `--begin -a -b --end`.
+    pub fn options_between_ids(&self, start_id: &str, end_id: &str) -> &[Opt] {
+        let position = |id: &str| self.options.iter().position(|opt| opt.id == id);
+        match (position(start_id), position(end_id)) {
+            (Some(start), Some(end)) if start < end => &self.options[start + 1..end],
+            _ => &[],
+        }
+    }
+}
+
+#[cfg(feature = "toml")]
+fn toml_array<'a, I: Iterator<Item = &'a str>>(items: I) -> String {
+    let items: Vec<String> = items.map(toml_string).collect();
+    alloc::format!("[{}]", items.join(", "))
+}
+
+#[cfg(feature = "toml")]
+fn toml_string(s: &str) -> String {
+    let mut result = String::with_capacity(s.len() + 2);
+    result.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => result.push_str("\\\""),
+            '\\' => result.push_str("\\\\"),
+            '\n' => result.push_str("\\n"),
+            '\t' => result.push_str("\\t"),
+            '\r' => result.push_str("\\r"),
+            c if c.is_control() => result.push_str(&alloc::format!("\\u{:04X}", c as u32)),
+            c => result.push(c),
+        }
+    }
+    result.push('"');
+    result
+}
+
+/// Structured option information.
+///
+/// This [`Opt`] struct represents organized information about single
+/// command-line option. Instances of this struct are usually created by
+/// [`OptSpecs::getopt`] method which returns an [`Args`] struct which
+/// have these [`Opt`] structs inside.
+///
+/// A programmer may need these when examining parsed command-line
+/// options. See the documentation of individual fields for more
+/// information. Also see [`Args`] struct and its methods.
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Opt {
+    /// Identifier for the option.
+    ///
+    /// Identifiers are defined with [`OptSpecs::option`] method before
+    /// parsing command-line arguments. After [`OptSpecs::getopt`]
+    /// parsing the same identifier is copied here and it confirms that
+    /// the option was indeed given in the command line.
+    pub id: String,
+
+    /// Option's name in the parsed command line.
+    ///
+    /// Option's name that was used in the command line. For short
+    /// options this is a single-character string. For long options the
+    /// name has more than one characters.
+    pub name: String,
+
+    /// The option requires a value.
+    ///
+    /// `true` means that the option was defined with value type
+    /// [`OptValue::Required`]. See [`OptSpecs::flag`] method for
+    /// more information. This field does not guarantee that there
+    /// actually was a value for the option in the command line.
+    pub value_required: bool,
+
+    /// Option's value.
+    ///
+    /// The value is a variant of enum [`Option`]. Value `None` means
+    /// that there is no value for the option. Value `Some(String)`
+    /// provides a value.
+    pub value: Option<String>,
+
+    /// Index of the command-line argument which had the option.
+    ///
+    /// The index is 0-based and it counts all items in the argument
+    /// iterator given to [`OptSpecs::getopt`] method. If the option's
+    /// value was given as the next command-line argument this index
+    /// still refers to the option itself, not the value. Options which
+    /// don't come from the command line, like the ones added with
+    /// `OptSpecs::option_parse_env` and [`OptSpecs::apply_defaults`]
+    /// methods, have index [`usize::MAX`].
+    pub source_index: usize,
+
+    /// The option was given without `-` or `--` prefix.
+    ///
+    /// `true` means that the option was defined with
+    /// [`OptSpecs::option_no_prefix`] method and it was given in the
+    /// command line as a bare word, like `build`.
+    pub no_prefix: bool,
+}
+
+impl Opt {
+    /// Return boolean whether the option is a long option.
+    ///
+    /// Long option names have two or more characters, like `file`
+    /// (entered as `--file` in the command line). Options without
+    /// prefix (see [`Opt::no_prefix`]) are not long options.
+    pub fn is_long(&self) -> bool {
+        !self.no_prefix && self.name.chars().count() > 1
+    }
+
+    /// Return boolean whether the option is a short option.
+    ///
+    /// Short option names are a single character, like `f` (entered as
+    /// `-f` in the command line). Options without prefix (see
+    /// [`Opt::no_prefix`]) are not short options.
+    pub fn is_short(&self) -> bool {
+        !self.no_prefix && self.name.chars().count() == 1
+    }
+
+    fn display_name(&self) -> String {
+        if self.no_prefix {
+            self.name.clone()
+        } else {
+            prefixed_name(&self.name)
+        }
+    }
+
+    /// Return boolean whether the option has a value.
+    ///
+    /// This is the same as checking if [`Opt::value`] field is `Some`.
+    pub fn has_value(&self) -> bool {
+        self.value.is_some()
+    }
+
+    /// Return option's value as a string slice.
+    ///
+    /// The return value is `Some(&str)` if [`Opt::value`] field has a
+    /// value or `None` otherwise.
+    pub fn value_str(&self) -> Option<&str> {
+        self.value.as_deref()
+    }
+}
+
+/// Format the parsed arguments as a normalized command line.
+///
+/// Options in [`Args::options`] field are formatted first (see the
+/// [`Display`](fmt::Display) implementation of [`Opt`]), then unknown
+/// options with `-` or `--` prefix, then option terminator `--` and
+/// other arguments (non-options) if there are any. Arguments are
+/// separated with a space character and they are not quoted.
+///
+/// Splitting the result at each space character and parsing it again
+/// with the same [`OptSpecs`] gives the same options and arguments,
+/// with these exceptions:
+///
+///   - An argument or a value has space characters.
+///   - Long option values are always written after `=` character, so
+///     the result can't be parsed with a different separator (see
+///     [`OptSpecs::long_value_separator`]).
+///   - An option's required value is missing (see
+///     [`Args::required_value_missing`]). When parsing again the option
+///     takes the next argument as its value.
+impl fmt::Display for Args {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut separator = "";
+        for opt in &self.options {
+            write!(f, "{}{}", separator, opt)?;
+            separator = " ";
+        }
+        for name in &self.unknown {
+            write!(f, "{}{}", separator, prefixed_name(name))?;
+            separator = " ";
+        }
+        if !self.other.is_empty() {
+            write!(f, "{}--", separator)?;
+            for arg in &self.other {
+                write!(f, " {}", arg)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Format the option as a command-line argument.
+///
+/// Short options are formatted like `-x` or `-fVALUE` and long options
+/// like `--foo` or `--foo=VALUE`. Options without prefix (see
+/// [`Opt::no_prefix`]) are formatted like `build` or `target VALUE`
+/// where the value is a separate argument. An empty value of a short
+/// option is written as a separate argument too: the result is `-f`
+/// followed by a space character.
+///
+/// [`Opt`] struct does not store how the value was given in the
+/// original command line, so the result is not necessarily the same.
+/// For example, `-f VALUE` and `--foo VALUE` are formatted as `-fVALUE`
+/// and `--foo=VALUE`. The argument is still parsed as the same option
+/// and value.
+impl fmt::Display for Opt {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = self.display_name();
+        match &self.value {
+            None => write!(f, "{}", name),
+            Some(v) if self.no_prefix => write!(f, "{} {}", name, v),
+            Some(v) if self.is_short() && v.is_empty() => write!(f, "{} ", name),
+            Some(v) if self.is_short() => write!(f, "{}{}", name, v),
+            Some(v) => write!(f, "{}={}", name, v),
+        }
+    }
+}
+
+/// Error for invalid option specification.
+///
+/// This is returned by [`OptSpecs::try_option`] method. Method
+/// [`OptSpecs::option`] and other methods panic with the same error
+/// message.
+
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum OptionSpecError {
+    /// Option's identifier is an empty string.
+    EmptyId,
+    /// Option's name is an empty string.
+    EmptyName,
+    /// Option with the same name has already been added. The value is
+    /// the name.
+    DuplicateName(String),
+    /// Option's name has illegal characters. The value is the name.
+    InvalidName(String),
+}
+
+impl fmt::Display for OptionSpecError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::EmptyId => write!(f, "Option's \"id\" must be at least 1 character long."),
+            Self::EmptyName => write!(f, "Option's \"name\" must be at least 1 character long."),
+            Self::DuplicateName(n) => {
+                write!(f, "No duplicates allowed for option's \"name\" ({}).", n)
+            }
+            Self::InvalidName(n) => write!(f, "Not a valid option name ({}).", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OptionSpecError {}
+
+/// Error found in parsed command line.
+///
+/// This is returned by [`Args::validate`] and
+/// [`OptSpecs::getopt_strict`] methods. Option names are in the same
+/// form as in the command line: with `-` or `--` prefix, or without
+/// prefix for options defined with [`OptSpecs::option_no_prefix`]
+/// method.
+
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// Option requires a value but it was not given.
+    RequiredValueMissing {
+        /// The option's name.
+        option_name: String,
+    },
+    /// Unknown option.
+    UnknownOption {
+        /// The option's name.
+        name: String,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::RequiredValueMissing { option_name } => {
+                write!(f, "Value is required for option '{}'.", option_name)
+            }
+            Self::UnknownOption { name } => write!(f, "Unknown option '{}'.", name),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseError {}
+
+/// Error for unexpected number of other arguments.
+///
+/// This is returned by [`Args::exactly_one_other`] method when the
+/// number of other arguments (non-options) is not exactly one.
+
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum OtherArgsError {
+    /// There were no other arguments.
+    Missing,
+    /// There were too many other arguments. The value is the number of
+    /// arguments.
+    TooMany(usize),
+}
+
+impl fmt::Display for OtherArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::Missing => write!(f, "Argument is missing."),
+            Self::TooMany(n) => write!(f, "Too many arguments ({}).", n),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for OtherArgsError {}
+
+/// Error for wrong number of other arguments.
+///
+/// This is returned by [`Args::check_at_most_one_other`],
+/// [`Args::check_exactly_n_other`] and [`Args::check_at_least_n_other`]
+/// methods when the number of other arguments (non-options) is not
+/// acceptable.
+
+#[derive(Debug, PartialEq)]
+pub struct ExtraArgsError {
+    /// The expected number of arguments: the maximum, the exact or the
+    /// minimum number, depending on the method.
+    pub expected: usize,
+    /// The number of arguments that were found.
+    pub found: usize,
+    /// The arguments after the first `expected` ones. This is empty if
+    /// there were too few arguments.
+    pub extra: Vec<String>,
+}
+
+impl fmt::Display for ExtraArgsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.found > self.expected {
+            write!(
+                f,
+                "Too many arguments ({} expected, {} given).",
+                self.expected, self.found
+            )
+        } else {
+            write!(
+                f,
+                "Too few arguments ({} expected, {} given).",
+                self.expected, self.found
+            )
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExtraArgsError {}
+
+/// Error for wrong number of options.
+///
+/// This is returned by [`Args::options_n`] method when an option was
+/// not given the expected number of times.
+
+#[derive(Debug, PartialEq)]
+pub struct ArityError {
+    /// Identifier of the option.
+    pub id: String,
+    /// The expected number of options.
+    pub expected: usize,
+    /// The number of options that were found.
+    pub found: usize,
+}
+
+impl fmt::Display for ArityError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Option \"{}\" must be given {} time(s), found {}.",
+            self.id, self.expected, self.found
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ArityError {}
+
+/// Create [`Args`] struct from a hash map.
+///
+/// Each `(name, value)` entry of the hash map becomes an [`Opt`] struct
+/// which has the `name` as its identifier and name, and the `value` as
+/// its value. Because there is no [`OptSpecs`] specification the names
+/// are only checked to be valid option names and the values are always
+/// stored as they are. The order of options is the iteration order of
+/// the hash map. See [`Args::from_map_with_specs`] for conversion that
+/// is validated against specification.
+///
+/// This is available only with the crate feature `std`.
+#[cfg(feature = "std")]
+impl TryFrom<std::collections::HashMap<String, String>> for Args {
+    type Error = ConversionError;
+
+    fn try_from(map: std::collections::HashMap<String, String>) -> Result<Self, Self::Error> {
+        let mut args = Args::new();
+
+        for (index, (name, value)) in map.into_iter().enumerate() {
+            let valid = match name.chars().count() {
+                1 => parser::is_valid_short_option_name(&name),
+                _ => parser::is_valid_long_option_name(&name),
+            };
+            if !valid {
+                return Err(ConversionError::InvalidName(name));
+            }
+
+            args.options.push(Opt {
+                id: name.clone(),
+                name,
+                value_required: false,
+                value: Some(value),
+                source_index: index,
+                no_prefix: false,
+            });
+        }
+        Ok(args)
+    }
+}
+
+/// Error for converting option names and values to [`Args`].
+///
+/// This is returned by [`Args::from_map_with_specs`] method and
+/// `TryFrom<HashMap<String, String>>` implementation of [`Args`]. Each
+/// variant has the option name which caused the error.
+
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ConversionError {
+    /// Option name is not in the specification.
+    UnknownOption(String),
+    /// Option name is not a valid short or long option name.
+    InvalidName(String),
+    /// Option does not accept a value but a value was given.
+    ValueNotAccepted(String),
+}
+
+impl fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::UnknownOption(n) => write!(f, "Unknown option \"{}\".", n),
+            Self::InvalidName(n) => write!(f, "Not a valid option name \"{}\".", n),
+            Self::ValueNotAccepted(n) => {
+                write!(f, "Option \"{}\" does not accept a value.", n)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ConversionError {}
+
+/// Violated option dependency.
+///
+/// This is returned by [`Args::check_dependencies`] method. See
+/// [`OptSpecs::option_depends_on`] method for defining dependencies.
+
+#[derive(Debug, PartialEq)]
+pub struct DependencyError {
+    /// Identifier of the option which was given in the command line.
+    pub dependent: String,
+    /// Name of the option which was given in the command line, with
+    /// `-` or `--` prefix, or without prefix for options defined with
+    /// [`OptSpecs::option_no_prefix`] method. This is `Some` only if
+    /// the dependency was defined with [`OptSpecs::option_requires_id`]
+    /// method, which links the dependency to an option name instead of
+    /// an identifier.
+    pub dependent_name: Option<String>,
+    /// Identifier of the option which is required but was not given.
+    pub required: String,
+}
+
+impl fmt::Display for DependencyError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let dependent = self.dependent_name.as_ref().unwrap_or(&self.dependent);
+        write!(
+            f,
+            "Option \"{}\" requires option \"{}\".",
+            dependent, self.required
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DependencyError {}
+
+/// Mutually exclusive options given together.
+///
+/// This is returned by [`Args::verify_exclusive_groups`] method. See
+/// [`OptSpecs::option_exclusive`] method for defining groups of
+/// mutually exclusive options.
+///
+/// The struct implements [`Display`](fmt::Display) trait which formats
+/// a message like `--json and --xml are mutually exclusive; please use
+/// only one`.
+
+#[derive(Debug, PartialEq)]
+pub struct ExclusiveGroupError {
+    /// Identifiers of the conflicting options in the order they were
+    /// first given in the command line.
+    pub ids: Vec<String>,
+    /// Names of the conflicting options as they were given in the
+    /// command line, with `-` or `--` prefix, or without prefix for
+    /// options defined with [`OptSpecs::option_no_prefix`] method.
+    /// Each element
+    /// corresponds to the element in [`ids`](ExclusiveGroupError::ids)
+    /// field.
+    pub names: Vec<String>,
+}
+
+impl fmt::Display for ExclusiveGroupError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let last = self.names.len().saturating_sub(1);
+        for (i, name) in self.names.iter().enumerate() {
+            match i {
+                0 => (),
+                _ if i == last => write!(f, " and ")?,
+                _ => write!(f, ", ")?,
+            }
+            write!(f, "{}", name)?;
+        }
+        write!(f, " are mutually exclusive; please use only one")
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ExclusiveGroupError {}
+
+#[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    #[test]
+    fn t_create_optspecs_010() {
+        let mut spec;
+        let mut expect;
+
+        spec = OptSpecs::new().option("help", "help", OptValue::None);
+        expect = OptSpec {
+            id: String::from("help"),
+            name: String::from("help"),
+            value_type: OptValue::None,
+            range: None,
+            category: Vec::new(),
+            no_prefix: false,
+        };
+        assert_eq!(1, spec.options.len());
+        assert_eq!(&expect, &spec.options[0]);
+        assert_eq!(COUNTER_LIMIT, spec.option_limit);
+        assert_eq!(COUNTER_LIMIT, spec.other_limit);
+        assert_eq!(COUNTER_LIMIT, spec.unknown_limit);
+
+        spec = spec.option("file", "f", OptValue::Optional);
+        expect = OptSpec {
+            id: String::from("file"),
+            name: String::from("f"),
+            value_type: OptValue::Optional,
+            range: None,
+            category: Vec::new(),
+            no_prefix: false,
+        };
+        assert_eq!(2, spec.options.len());
+        assert_eq!(&expect, &spec.options[1]);
+
+        spec = spec.option("file", "file", OptValue::Required);
+        expect = OptSpec {
+            id: String::from("file"),
+            name: String::from("file"),
+            value_type: OptValue::Required,
+            range: None,
+            category: Vec::new(),
+            no_prefix: false,
+        };
+        assert_eq!(3, spec.options.len());
+        assert_eq!(&expect, &spec.options[2]);
+
+        spec = spec.flag(OptFlags::OptionsEverywhere);
+        assert_eq!(1, spec.flags.len()); // Length 1
+        assert_eq!(true, spec.is_flag(OptFlags::OptionsEverywhere));
+        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
+        assert_eq!(2, spec.flags.len()); // Length 2
+        assert_eq!(true, spec.is_flag(OptFlags::PrefixMatchLongOptions));
+        spec = spec.flag(OptFlags::OptionsEverywhere);
+        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
+        assert_eq!(2, spec.flags.len()); // Length still 2
+
+        spec = spec.limit_options(9);
+        spec = spec.limit_other_args(10);
+        spec = spec.limit_unknown_options(3);
+        assert_eq!(9, spec.option_limit);
+        assert_eq!(10, spec.other_limit);
+        assert_eq!(3, spec.unknown_limit);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_020() {
+        OptSpecs::new().option("", "h", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_030() {
+        OptSpecs::new()
+            .option("h", "h", OptValue::None)
+            .option("h", "h", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_040() {
+        OptSpecs::new().option("h", "", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_050() {
+        OptSpecs::new().option("h", "-", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_060() {
+        OptSpecs::new().option("h", " ", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_070() {
+        OptSpecs::new().option("h", "hh ", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_080() {
+        OptSpecs::new().option("h", "hh=hh", OptValue::None);
+    }
+
+    #[test]
+    fn t_is_flag() {
+        let mut spec = OptSpecs::new().flag(OptFlags::OptionsEverywhere);
+        assert_eq!(true, spec.is_flag(OptFlags::OptionsEverywhere));
+
+        spec = spec.flag(OptFlags::PrefixMatchLongOptions);
+        assert_eq!(true, spec.is_flag(OptFlags::PrefixMatchLongOptions));
+    }
+
+    #[test]
+    fn t_parsed_output_010() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .getopt(["-h", "--help", "-f123", "-f", "456", "foo", "bar"]);
+
+        assert_eq!(true, parsed.option_exists("help"));
+        assert_eq!(true, parsed.option_exists("file"));
+        assert_eq!(false, parsed.option_exists("x"));
+
+        assert_eq!("h", parsed.options_first("help").unwrap().name);
+        assert_eq!("help", parsed.options_last("help").unwrap().name);
+        assert_eq!("help", parsed.options_first("help").unwrap().id);
+        assert_eq!("help", parsed.options_last("help").unwrap().id);
+        assert_eq!(false, parsed.options_first("help").unwrap().value_required);
+        assert_eq!(false, parsed.options_last("help").unwrap().value_required);
+
+        assert_eq!("f", parsed.options_first("file").unwrap().name);
+        assert_eq!(
+            "123",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(
+            "456",
+            parsed.options_last("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
+
+        assert_eq!("foo", parsed.other[0]);
+        assert_eq!("bar", parsed.other[1]);
+    }
+
+    #[test]
+    fn t_parsed_output_020() {
+        let parsed = OptSpecs::new()
+            .limit_options(1)
+            .limit_other_args(2)
+            .option("help", "h", OptValue::None)
+            .getopt(["-h", "foo", "-h"]);
+
+        assert_eq!("h", parsed.options_first("help").unwrap().name);
+        assert_eq!(2, parsed.other.len());
+        assert_eq!("foo", parsed.other[0]);
+        assert_eq!("-h", parsed.other[1]);
+    }
+
+    #[test]
+    fn t_parsed_output_030() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .getopt(["-h", "foo", "--help", "--file=123", "bar", "--file", "456"]);
+
+        assert_eq!("h", parsed.options_first("help").unwrap().name);
+        assert_eq!("help", parsed.options_last("help").unwrap().name);
+        assert_eq!(
+            "123",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(
+            "456",
             parsed.options_last("file").unwrap().value.clone().unwrap()
         );
-        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
+        assert_eq!("foo", parsed.other[0]);
+        assert_eq!("bar", parsed.other[1]);
+    }
+
+    #[test]
+    fn t_parsed_output_040() {
+        let parsed = OptSpecs::new()
+            .option("debug", "d", OptValue::Optional)
+            .option("verbose", "verbose", OptValue::Optional)
+            .getopt(["-d1", "-d", "--verbose", "--verbose=123"]);
+
+        assert_eq!(
+            "1",
+            parsed
+                .options_first("debug")
+                .unwrap()
+                .value
+                .clone()
+                .unwrap()
+        );
+        assert_eq!(None, parsed.options_last("debug").unwrap().value);
+        assert_eq!(false, parsed.options_last("debug").unwrap().value_required);
+
+        assert_eq!(None, parsed.options_first("verbose").unwrap().value);
+        assert_eq!(
+            "123",
+            parsed
+                .options_last("verbose")
+                .unwrap()
+                .value
+                .clone()
+                .unwrap()
+        );
+        assert_eq!(
+            false,
+            parsed.options_last("verbose").unwrap().value_required
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_050() {
+        let parsed = OptSpecs::new()
+            .option("debug", "d", OptValue::Optional)
+            .getopt(["-abcd", "-adbc"]);
+
+        assert_eq!(None, parsed.options_first("debug").unwrap().value);
+        assert_eq!(
+            "bc",
+            parsed.options_last("debug").unwrap().value.clone().unwrap()
+        );
+
+        assert_eq!(3, parsed.unknown.len());
+        assert_eq!("a", parsed.unknown[0]);
+        assert_eq!("b", parsed.unknown[1]);
+        assert_eq!("c", parsed.unknown[2]);
+    }
+
+    #[test]
+    fn t_parsed_output_060() {
+        let parsed = OptSpecs::new()
+            .option("aaa", "bbb", OptValue::None)
+            .option("aaa", "c", OptValue::None)
+            .option("aaa", "d", OptValue::None)
+            .option("aaa", "eee", OptValue::None)
+            .getopt(["--bbb", "-cd", "--eee"]);
+
+        let m: Vec<&Opt> = parsed.options_all("aaa").collect();
+        assert_eq!("bbb", m[0].name);
+        assert_eq!("c", m[1].name);
+        assert_eq!("d", m[2].name);
+        assert_eq!("eee", m[3].name);
+    }
+
+    #[test]
+    fn t_parsed_output_070() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::PrefixMatchLongOptions)
+            .option("version", "version", OptValue::None)
+            .option("verbose", "verbose", OptValue::None)
+            .getopt(["--ver", "--verb", "--versi", "--verbose"]);
+
+        assert_eq!("ver", parsed.unknown[0]);
+        assert_eq!("verb", parsed.options_first("verbose").unwrap().name);
+        assert_eq!("verbose", parsed.options_last("verbose").unwrap().name);
+        assert_eq!("version", parsed.options_first("version").unwrap().id);
+        assert_eq!("versi", parsed.options_first("version").unwrap().name);
+    }
+
+    #[test]
+    fn t_parsed_output_080() {
+        let parsed = OptSpecs::new()
+            // .flag(OptFlags::PrefixMatchLongOptions) Must be commented!
+            .option("version", "version", OptValue::None)
+            .option("verbose", "verbose", OptValue::None)
+            .getopt(["--version", "--ver", "--verb", "--versi", "--verbose"]);
+
+        assert_eq!("ver", parsed.unknown[0]);
+        assert_eq!("verb", parsed.unknown[1]);
+        assert_eq!("versi", parsed.unknown[2]);
+        assert_eq!("version", parsed.options_first("version").unwrap().name);
+        assert_eq!("verbose", parsed.options_first("verbose").unwrap().name);
+    }
+
+    #[test]
+    fn t_parsed_output_085() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::PrefixMatchLongOptions)
+            .option("foo1", "foo", OptValue::None)
+            .option("foo2", "foo-longer", OptValue::None)
+            .getopt(["--fo", "--foo", "--foo-", "--foo-longer"]);
+
+        assert_eq!("fo", parsed.unknown[0]);
+        assert_eq!("foo", parsed.options_first("foo1").unwrap().name);
+        assert_eq!("foo", parsed.options_last("foo1").unwrap().name);
+        assert_eq!("foo-", parsed.options_first("foo2").unwrap().name);
+        assert_eq!("foo-longer", parsed.options_last("foo2").unwrap().name);
+    }
+
+    #[test]
+    fn t_parsed_output_090() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .option("file", "file", OptValue::Required)
+            .getopt(["-h", "foo", "--file=123", "--", "bar", "--file", "456"]);
+
+        assert_eq!("h", parsed.options_first("help").unwrap().name);
+        assert_eq!("file", parsed.options_first("file").unwrap().name);
+        assert_eq!(
+            "123",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+
+        assert_eq!(4, parsed.other.len());
+        assert_eq!("foo", parsed.other[0]);
+        assert_eq!("bar", parsed.other[1]);
+        assert_eq!("--file", parsed.other[2]);
+        assert_eq!("456", parsed.other[3]);
+    }
+
+    #[test]
+    fn t_parsed_output_100() {
+        let parsed = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .getopt(["--file=", "--file"]);
+
+        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
+        assert_eq!(
+            "",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+    }
+
+    #[test]
+    fn t_parsed_output_110() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("debug", "d", OptValue::Required)
+            .getopt(["-fx", "-d", "", "-f"]);
+
+        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
+        assert_eq!(
+            "x",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        assert_eq!(
+            "",
+            parsed
+                .options_first("debug")
+                .unwrap()
+                .value
+                .clone()
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_120() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("debug", "d", OptValue::Required)
+            .getopt(["-f123", "-d", "", "-f", "456", "-f"]);
+
+        let f: Vec<&String> = parsed.options_value_all("file").collect();
+        assert_eq!(2, f.len());
+        assert_eq!("123", f[0]);
+        assert_eq!("456", f[1]);
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(1, d.len());
+        assert_eq!("", d[0]);
+
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        let m: Vec<&Opt> = parsed.required_value_missing().collect();
+        assert_eq!(1, m.len());
+        assert_eq!("f", m[0].name);
+    }
+
+    #[test]
+    fn t_parsed_output_125() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("debug", "d", OptValue::RequiredNonEmpty)
+            .getopt(["-f123", "-d", "", "-f", "456", "-f"]);
+
+        let f: Vec<&String> = parsed.options_value_all("file").collect();
+        assert_eq!(2, f.len());
+        assert_eq!("123", f[0]);
+        assert_eq!("456", f[1]);
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(0, d.len());
+
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        let m: Vec<&Opt> = parsed.required_value_missing().collect();
+        assert_eq!(2, m.len());
+        assert_eq!("d", m[0].name);
+        assert_eq!("f", m[1].name);
+    }
+
+    #[test]
+    fn t_parsed_output_130() {
+        let parsed = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .option("debug", "debug", OptValue::Required)
+            .getopt(["--file=123", "--debug", "", "--file", "456", "--file"]);
+
+        let f: Vec<&String> = parsed.options_value_all("file").collect();
+        assert_eq!(2, f.len());
+        assert_eq!("123", f[0]);
+        assert_eq!("456", f[1]);
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(1, d.len());
+        assert_eq!("", d[0]);
+
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        let m: Vec<&Opt> = parsed.required_value_missing().collect();
+        assert_eq!(1, m.len());
+        assert_eq!("file", m[0].name);
+    }
+
+    #[test]
+    fn t_parsed_output_135() {
+        let parsed = OptSpecs::new()
+            .option("file", "file", OptValue::RequiredNonEmpty)
+            .option("debug", "debug", OptValue::RequiredNonEmpty)
+            .getopt(["--file=123", "--debug", "", "--file", "456", "--file="]);
+
+        let f: Vec<&String> = parsed.options_value_all("file").collect();
+        assert_eq!(2, f.len());
+        assert_eq!("123", f[0]);
+        assert_eq!("456", f[1]);
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(0, d.len());
+
+        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        let m: Vec<&Opt> = parsed.required_value_missing().collect();
+        assert_eq!(2, m.len());
+        assert_eq!("debug", m[0].name);
+        assert_eq!("file", m[1].name);
+    }
+
+    #[test]
+    fn t_parsed_output_137() {
+        let parsed = OptSpecs::new()
+            .option("debug", "d", OptValue::OptionalNonEmpty)
+            .option("debug", "debug", OptValue::OptionalNonEmpty)
+            .getopt([
+                "-d",
+                "-d123",
+                "--debug",
+                "--debug=",
+                "--debug=456",
+                "--debug=",
+            ]);
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(2, d.len());
+        assert_eq!("123", d[0]);
+        assert_eq!("456", d[1]);
+        assert_eq!("123", parsed.options_value_first("debug").unwrap());
+        assert_eq!("456", parsed.options_value_last("debug").unwrap());
+    }
+
+    #[test]
+    fn t_parsed_output_140() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("debug", "d", OptValue::Optional)
+            .option("debug", "debug", OptValue::Optional)
+            .getopt([
+                "-d",
+                "-d123",
+                "-d",
+                "--debug",
+                "--debug=",
+                "foo",
+                "--debug=456",
+                "-d",
+            ]);
+
+        let d: Vec<&Opt> = parsed.options_all("debug").collect();
+        assert_eq!(7, d.len());
+
+        let d: Vec<&String> = parsed.options_value_all("debug").collect();
+        assert_eq!(3, d.len());
+        assert_eq!("123", d[0]);
+        assert_eq!("", d[1]);
+        assert_eq!("456", d[2]);
+        assert_eq!("123", parsed.options_value_first("debug").unwrap());
+        assert_eq!("456", parsed.options_value_last("debug").unwrap());
+
+        assert_eq!(None, parsed.options_value_first("not-at-all"));
+        assert_eq!(None, parsed.options_value_last("not-at-all"));
+
+        assert_eq!("foo", parsed.other[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_150() {
+        let parsed = OptSpecs::new().limit_unknown_options(6).getopt([
+            "-abcd",
+            "-e",
+            "--debug",
+            "-x", // Won't be listed in unknown because of limit.
+            "--",
+            "--debug=",
+            "foo",
+            "--debug=456",
+        ]);
+
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(3, parsed.other.len());
+        assert_eq!(6, parsed.unknown.len());
+        assert_eq!(vec!["a", "b", "c", "d", "e", "debug"], parsed.unknown);
+    }
+
+    #[test]
+    fn t_parsed_output_160() {
+        let parsed = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .getopt(["--file", "--", "--", "--"]);
+
+        assert_eq!(
+            "--",
+            parsed.options_first("file").unwrap().value.clone().unwrap()
+        );
+        assert_eq!(1, parsed.other.len());
+        assert_eq!("--", parsed.other[0]);
+
+        assert_eq!(0, parsed.required_value_missing().count());
+    }
+
+    #[test]
+    fn t_parsed_output_170() {
+        let parsed = OptSpecs::new().getopt(["foo", "bar"]);
+
+        assert_eq!(None, parsed.options_first("not-at-all"));
+        assert_eq!(None, parsed.options_last("not-at-all"));
+    }
+
+    #[test]
+    fn t_parsed_output_180() {
+        let parsed = OptSpecs::new()
+            .limit_unknown_options(3)
+            .option("bar", "bar", OptValue::None)
+            .getopt(["-aaa", "--foo", "--foo", "--bar=", "--bar=", "-x"]);
+
+        assert_eq!(3, parsed.unknown.len());
+        assert_eq!("a", parsed.unknown[0]);
+        assert_eq!("foo", parsed.unknown[1]);
+        assert_eq!("bar=", parsed.unknown[2]);
+    }
+
+    #[test]
+    fn t_parsed_output_190() {
+        let parsed = OptSpecs::new()
+            .option("äiti", "äiti", OptValue::Required)
+            .option("€uro", "€uro", OptValue::Required)
+            .getopt(["--äiti=ööö", "--€uro", "€€€", "--äiti", "ää", "--äiti"]);
+
+        let a: Vec<&String> = parsed.options_value_all("äiti").collect();
+        assert_eq!(2, a.len());
+        assert_eq!("ööö", a[0]);
+        assert_eq!("ää", a[1]);
+        assert_eq!("ööö", parsed.options_value_first("äiti").unwrap());
+        assert_eq!("ää", parsed.options_value_last("äiti").unwrap());
+
+        let e: Vec<&String> = parsed.options_value_all("€uro").collect();
+        assert_eq!(1, e.len());
+        assert_eq!("€€€", e[0]);
+        assert_eq!("€€€", parsed.options_value_first("€uro").unwrap());
+        assert_eq!("€€€", parsed.options_value_last("€uro").unwrap());
+
+        assert_eq!(None, parsed.options_last("äiti").unwrap().value);
+
+        let m: Vec<&Opt> = parsed.required_value_missing().collect();
+        assert_eq!(1, m.len());
+        assert_eq!("äiti", m[0].name);
+        assert_eq!(None, m[0].value);
+    }
+
+    #[test]
+    fn t_parsed_output_195() {
+        let parsed = OptSpecs::new().getopt(["-ä€", "--€uro", "äää", "€€€"]);
+
+        assert_eq!(2, parsed.other.len());
+        assert_eq!("äää", parsed.other[0]);
+        assert_eq!("€€€", parsed.other[1]);
+
+        assert_eq!(3, parsed.unknown.len());
+        assert_eq!("ä", parsed.unknown[0]);
+        assert_eq!("€", parsed.unknown[1]);
+        assert_eq!("€uro", parsed.unknown[2]);
+    }
+
+    #[test]
+    fn t_parsed_output_200() {
+        let parsed = OptSpecs::new().limit_other_args(5).getopt(1..10);
+        assert_eq!(5, parsed.other.len());
+        assert_eq!(vec!["1", "2", "3", "4", "5"], parsed.other);
+    }
+
+    #[test]
+    fn t_parsed_output_210() {
+        let parsed = OptSpecs::new().limit_other_args(0).getopt(1..10);
+        assert_eq!(0, parsed.other.len());
+    }
+
+    #[test]
+    fn t_parsed_output_220() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("help", "help", OptValue::None)
+            .limit_options(3)
+            .limit_other_args(1)
+            .limit_unknown_options(3)
+            .getopt([
+                "--unknown",
+                "--help=",
+                "-ab",
+                "-f",
+                "one",
+                "-ftwo",
+                "--file",
+                "three",
+                "--file",
+                "four",
+                "other1",
+                "other2",
+            ]);
+
+        assert_eq!(3, parsed.options.len());
+        assert_eq!(
+            vec!["one", "two", "three"],
+            parsed.options_value_all("file").collect::<Vec<&String>>()
+        );
+
+        assert_eq!(1, parsed.other.len());
+        assert_eq!("other1", parsed.other[0]);
+
+        assert_eq!(3, parsed.unknown.len());
+        assert_eq!(vec!["unknown", "help=", "a"], parsed.unknown);
+    }
+
+    #[test]
+    fn t_parsed_output_230() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .limit_options(3)
+            .getopt(["-f", "one", "-ftwo", "--file=three", "--unknown"]);
+
+        assert_eq!(
+            vec!["one", "two", "three"],
+            parsed.options_value_all("file").collect::<Vec<&String>>()
+        );
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("unknown", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_240() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .limit_options(3)
+            .getopt(["-xhhhh"]);
+
+        assert_eq!(3, parsed.options.len());
+        assert_eq!(true, parsed.options_first("help").is_some());
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("x", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_250() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .limit_options(3)
+            .getopt(["-x", "-h", "-h", "-h", "-h"]);
+
+        assert_eq!(3, parsed.options.len());
+        assert_eq!(true, parsed.options_first("help").is_some());
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("x", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_260() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .limit_options(3)
+            .getopt(["-x", "-h", "-h", "--", "-h", "-h"]);
+
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(true, parsed.options_first("help").is_some());
+        assert_eq!(2, parsed.other.len());
+        assert_eq!(vec!["-h", "-h"], parsed.other);
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("x", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_270() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .limit_options(1)
+            .limit_other_args(2)
+            .limit_unknown_options(1)
+            .getopt(["bar", "-habf", "123", "foo"]);
+
+        // "123" must be parsed as "f" option's value even though it is
+        // beyond limit_options.
+        assert_eq!(true, parsed.options_first("help").is_some());
+        assert_eq!(false, parsed.options_first("file").is_some());
+        assert_eq!(2, parsed.other.len());
+        assert_eq!("bar", parsed.other[0]);
+        assert_eq!("foo", parsed.other[1]);
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("a", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_280() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "help", OptValue::None)
+            .option("file", "file", OptValue::Required)
+            .limit_options(1)
+            .limit_other_args(2)
+            .limit_unknown_options(1)
+            .getopt(["bar", "--help", "-ab", "--file", "123", "foo"]);
+
+        // "123" must be parsed as "--file" option's value even though
+        // it is beyond limit_options.
+        assert_eq!(true, parsed.options_first("help").is_some());
+        assert_eq!(false, parsed.options_first("file").is_some());
+        assert_eq!(2, parsed.other.len());
+        assert_eq!("bar", parsed.other[0]);
+        assert_eq!("foo", parsed.other[1]);
+        assert_eq!(1, parsed.unknown.len());
+        assert_eq!("a", parsed.unknown[0]);
+    }
+
+    #[test]
+    fn t_parsed_output_290() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::RequiredNonEmpty)
+            .option("debug", "d", OptValue::RequiredNonEmpty)
+            .getopt(["-f1", "-d", "", "-f", "", "-f", "2", "-f"]);
+
+        let mut i = parsed.options_all("file").rev();
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!(None, i.next());
+
+        let mut i = parsed.options_all("debug").rev();
+        assert_eq!("d", i.next().unwrap().name);
+        assert_eq!(None, i.next());
+
+        let mut i = parsed.options_value_all("file").rev();
+        assert_eq!("2", i.next().unwrap());
+        assert_eq!("1", i.next().unwrap());
+        assert_eq!(None, i.next());
+
+        let mut i = parsed.required_value_missing().rev();
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!("f", i.next().unwrap().name);
+        assert_eq!("d", i.next().unwrap().name);
+        assert_eq!(None, i.next());
+    }
+
+    #[test]
+    fn t_parsed_output_300() {
+        let parsed = OptSpecs::new()
+            .option("debug", "d", OptValue::Optional)
+            .option("debug", "debug", OptValue::Optional)
+            .option("help", "h", OptValue::None)
+            .getopt(["-d", "-h", "--debug=1", "-d2", "--debug"]);
+
+        let v: Vec<(&str, Option<&str>)> = parsed.option_group_values("debug").collect();
+        assert_eq!(
+            vec![
+                ("d", None),
+                ("debug", Some("1")),
+                ("d", Some("2")),
+                ("debug", None)
+            ],
+            v
+        );
+
+        let mut i = parsed.option_group_values("help");
+        assert_eq!(Some(("h", None)), i.next());
+        assert_eq!(None, i.next());
+
+        assert_eq!(None, parsed.option_group_values("not-at-all").next());
+    }
+
+    #[test]
+    fn t_parsed_output_310() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("debug", "d", OptValue::Optional)
+            .getopt(["--file", "foo", "--file=", "-d", "-d1", "-f"]);
+
+        let m: Vec<&Opt> = parsed.options_not_having_value("file").collect();
+        assert_eq!(1, m.len());
+        assert_eq!("f", m[0].name);
+        assert_eq!(true, m[0].value_required);
+
+        let m: Vec<&Opt> = parsed.options_not_having_value("debug").collect();
+        assert_eq!(1, m.len());
+        assert_eq!("d", m[0].name);
+        assert_eq!(false, m[0].value_required);
+
+        assert_eq!(0, parsed.options_not_having_value("not-at-all").count());
+    }
+
+    #[test]
+    fn t_parsed_output_320() {
+        let parsed = OptSpecs::new().getopt(["1", "22", "x", "333"]);
+
+        let v: Vec<Result<u32, _>> = parsed.collect_other_parsed();
+        assert_eq!(4, v.len());
+        assert_eq!(Ok(1), v[0]);
+        assert_eq!(Ok(22), v[1]);
+        assert_eq!(true, v[2].is_err());
+        assert_eq!(Ok(333), v[3]);
+
+        assert_eq!(true, parsed.collect_other_all_parsed::<u32>().is_err());
+
+        let parsed = OptSpecs::new().getopt(["1", "22", "333"]);
+        assert_eq!(
+            Ok(vec![1, 22, 333]),
+            parsed.collect_other_all_parsed::<u32>()
+        );
+
+        let parsed = OptSpecs::new().getopt([""; 0]);
+        assert_eq!(0, parsed.collect_other_parsed::<u32>().len());
+        assert_eq!(Ok(vec![]), parsed.collect_other_all_parsed::<u32>());
+    }
+
+    #[test]
+    fn t_parsed_output_330() {
+        let parsed = OptSpecs::new().getopt(["foo"]);
+        assert_eq!(Ok("foo"), parsed.exactly_one_other());
+
+        let parsed = OptSpecs::new().getopt([""; 0]);
+        assert_eq!(Err(OtherArgsError::Missing), parsed.exactly_one_other());
+
+        let parsed = OptSpecs::new().getopt(["foo", "bar", "baz"]);
+        assert_eq!(Err(OtherArgsError::TooMany(3)), parsed.exactly_one_other());
+    }
+
+    #[test]
+    fn t_parsed_output_340() {
+        let specs = OptSpecs::new()
+            .option_range("level", "l", 1, 9)
+            .option_range("level", "level", 1, 9)
+            .option("file", "f", OptValue::Required);
+        let parsed = specs.getopt([
+            "-l5",
+            "--level=0",
+            "-lx",
+            "-f",
+            "100",
+            "--level",
+            "10",
+            "-l",
+        ]);
+
+        let v = parsed.range_violations(&specs);
+        assert_eq!(3, v.len());
+        assert_eq!("level", v[0].0.name);
+        assert_eq!("0", v[0].0.value.clone().unwrap());
+        assert_eq!("Value is out of range.", v[0].1);
+        assert_eq!("l", v[1].0.name);
+        assert_eq!("Value is not an integer.", v[1].1);
+        assert_eq!("10", v[2].0.value.clone().unwrap());
+
+        assert_eq!(true, parsed.options_first("level").unwrap().value_required);
+        assert_eq!(1, parsed.required_value_missing().count());
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_090() {
+        OptSpecs::new().option_range("level", "l", 9, 1);
+    }
+
+    #[test]
+    fn t_parsed_output_350() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("file", "file", OptValue::RequiredNonEmpty)
+            .option("debug", "debug", OptValue::Optional);
+
+        let args = Args::from_map_with_specs([("file", "foo"), ("h", ""), ("debug", "")], &specs);
+        let args = args.unwrap();
+        assert_eq!(3, args.options.len());
+        assert_eq!("foo", args.options_value_first("file").unwrap());
+        assert_eq!(true, args.options_first("file").unwrap().value_required);
+        assert_eq!(None, args.options_first("help").unwrap().value);
+        assert_eq!("", args.options_value_first("debug").unwrap());
+        assert_eq!(0, args.other.len());
+        assert_eq!(0, args.unknown.len());
+
+        let args = Args::from_map_with_specs([("file", "")], &specs).unwrap();
+        assert_eq!(1, args.required_value_missing().count());
+
+        assert_eq!(
+            Err(ConversionError::UnknownOption(String::from("x"))),
+            Args::from_map_with_specs([("file", "foo"), ("x", "")], &specs)
+        );
+        assert_eq!(
+            Err(ConversionError::ValueNotAccepted(String::from("h"))),
+            Args::from_map_with_specs([("h", "1")], &specs)
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn t_parsed_output_355() {
+        let mut map = std::collections::HashMap::new();
+        map.insert(String::from("file"), String::from("foo"));
+        let args = Args::try_from(map).unwrap();
+        assert_eq!("file", args.options[0].id);
+        assert_eq!("foo", args.options_value_first("file").unwrap());
+
+        let mut map = std::collections::HashMap::new();
+        map.insert(String::from("-x"), String::new());
+        assert_eq!(
+            Err(ConversionError::InvalidName(String::from("-x"))),
+            Args::try_from(map)
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_360() {
+        let parsed = OptSpecs::new()
+            .option("foo", "foo", OptValue::Optional)
+            .option("foo", "f", OptValue::Optional)
+            .getopt(["--foo=", "--foo=bar", "-f", "-fbaz", "--foo="]);
+
+        assert_eq!("", parsed.options_value_first("foo").unwrap());
+        assert_eq!(Some("bar"), parsed.option_value_first_non_empty("foo"));
+        assert_eq!("", parsed.options_value_last("foo").unwrap());
+        assert_eq!(Some("baz"), parsed.option_value_last_non_empty("foo"));
+
+        let parsed = OptSpecs::new()
+            .option("foo", "foo", OptValue::Optional)
+            .getopt(["--foo=", "--foo"]);
+        assert_eq!(None, parsed.option_value_first_non_empty("foo"));
+        assert_eq!(None, parsed.option_value_last_non_empty("foo"));
+        assert_eq!(None, parsed.option_value_last_non_empty("not-at-all"));
+    }
+
+    #[test]
+    fn t_parsed_output_370() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .getopt(["-h", "-", "-h"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["-", "-h"], parsed.other);
+        assert_eq!(0, parsed.unknown.len());
+
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .getopt(["-h", "-", "-h"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(vec!["-"], parsed.other);
+        assert_eq!(0, parsed.unknown.len());
+    }
+
+    #[test]
+    fn t_parsed_output_380() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .getopt(["-hab", "--foo", "bar"]);
+
+        let (args, unknown) = parsed.partition_known_unknown();
+        assert_eq!(vec!["a", "b", "foo"], unknown);
+        assert_eq!(0, args.unknown.len());
+        assert_eq!(1, args.options.len());
+        assert_eq!(vec!["bar"], args.other);
+    }
+
+    #[test]
+    fn t_parsed_output_390() {
+        let specs = OptSpecs::new()
+            .option("output", "o", OptValue::Required)
+            .option("encoding", "e", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .option_depends_on("encoding", "output")
+            .option_depends_on("verbose", "output")
+            .option_depends_on("verbose", "output");
+        assert_eq!(2, specs.dependencies.len());
+
+        let parsed = specs.getopt(["-e", "utf-8", "-o", "file"]);
+        assert_eq!(0, parsed.check_dependencies(&specs).len());
+
+        let parsed = specs.getopt(["-v", "-e", "utf-8"]);
+        let e = parsed.check_dependencies(&specs);
+        assert_eq!(2, e.len());
+        assert_eq!("encoding", e[0].dependent);
+        assert_eq!("output", e[0].required);
+        assert_eq!("verbose", e[1].dependent);
+        assert_eq!("output", e[1].required);
+    }
+
+    #[test]
+    fn t_parsed_output_400() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("help", "h", OptValue::None)
+            .getopt(["foo", "-f", "123", "-xhf456", "--file", "789", "-h"]);
+
+        let v: Vec<usize> = parsed.options.iter().map(|o| o.source_index).collect();
+        assert_eq!(vec![1, 3, 3, 4, 6], v);
+    }
+
+    #[test]
+    fn t_parsed_output_410() {
+        let specs = OptSpecs::new()
+            .option("json", "json", OptValue::None)
+            .option("json", "j", OptValue::None)
+            .option("xml", "xml", OptValue::None)
+            .option("csv", "c", OptValue::None)
+            .option("help", "h", OptValue::None)
+            .option_exclusive(&["json", "xml", "csv"]);
+
+        let parsed = specs.getopt(["-j", "--json", "-h"]);
+        assert_eq!(Ok(()), parsed.verify_exclusive_groups(&specs));
+
+        let parsed = specs.getopt(["-h", "--xml", "--json"]);
+        let e = parsed.verify_exclusive_groups(&specs).unwrap_err();
+        assert_eq!(vec!["xml", "json"], e.ids);
+        assert_eq!(vec!["--xml", "--json"], e.names);
+        assert_eq!(
+            "--xml and --json are mutually exclusive; please use only one",
+            e.to_string()
+        );
+
+        let parsed = specs.getopt(["-c", "--xml", "-j"]);
+        let e = parsed.verify_exclusive_groups(&specs).unwrap_err();
+        assert_eq!(
+            "-c, --xml and -j are mutually exclusive; please use only one",
+            e.to_string()
+        );
+    }
+
+    #[test]
+    fn t_create_optspecs_100() {
+        let spec = OptSpecs::new()
+            .option_short_only("help", 'h', OptValue::None)
+            .option_short_only("euro", '€', OptValue::Required)
+            .option_long_only("help", "help", OptValue::None);
+
+        assert_eq!(3, spec.options.len());
+        assert_eq!("h", spec.options[0].name);
+        assert_eq!("€", spec.options[1].name);
+        assert_eq!(OptValue::Required, spec.options[1].value_type);
+        assert_eq!("help", spec.options[2].name);
+        assert_eq!("help", spec.options[2].id);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_110() {
+        OptSpecs::new().option_long_only("help", "h", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_120() {
+        OptSpecs::new().option_short_only("dash", '-', OptValue::None);
+    }
+
+    #[test]
+    fn t_parsed_output_420() {
+        let specs = OptSpecs::new().option("help", "h", OptValue::None);
+        assert_eq!(true, specs.getopt(["-h"]).has_any_option());
+        assert_eq!(false, specs.getopt(["-x", "foo"]).has_any_option());
+        assert_eq!(false, specs.getopt([""; 0]).has_any_option());
+    }
+
+    #[test]
+    fn t_parsed_output_430() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .limit_options(1)
+            .getopt(["-f1", "-f", "2", "--foo", "-f3", "bar", "-f4"]);
+
+        assert_eq!(1, parsed.options.len());
+        assert_eq!("1", parsed.options_value_first("file").unwrap());
+        assert_eq!(vec!["foo"], parsed.unknown);
+        assert_eq!(vec!["bar", "-f4"], parsed.other);
+    }
+
+    #[test]
+    fn t_parsed_output_440() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("help", "h", OptValue::None)
+            .option("debug", "debug", OptValue::Optional)
+            .getopt(["-f1", "-h", "--debug", "--debug=2", "-f", ""]);
+
+        assert_eq!(
+            vec![
+                (String::from("file"), String::from("1")),
+                (String::from("debug"), String::from("2")),
+                (String::from("file"), String::new()),
+            ],
+            parsed.into_pairs()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_450() {
+        let parsed = OptSpecs::new()
+            .option("foo", "foo", OptValue::Required)
+            .getopt(["--foo=bar=baz", "--foo===baz", "--foo=="]);
+
+        let v: Vec<&String> = parsed.options_value_all("foo").collect();
+        assert_eq!(vec!["bar=baz", "==baz", "="], v);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn t_parsed_output_460() {
+        let var = "JUST_GETOPT_TEST_460";
+        std::env::set_var(var, "env");
+        let specs = OptSpecs::new().option("file", "f", OptValue::Required);
+
+        let parsed = specs.getopt(["-fcmd"]);
+        assert_eq!(
+            Some(String::from("cmd")),
+            parsed.option_env_value("file", var)
+        );
+
+        let parsed = specs.getopt(["-f"]);
+        assert_eq!(
+            Some(String::from("env")),
+            parsed.option_env_value("file", var)
+        );
+
+        std::env::remove_var(var);
+        assert_eq!(None, parsed.option_env_value("file", var));
+    }
+
+    #[test]
+    fn t_create_optspecs_130() {
+        let spec = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("json", "json", OptValue::None)
+            .option_exclusive(&["help", "json"])
+            .option_depends_on("json", "help")
+            .flag(OptFlags::OptionsEverywhere)
+            .limit_options(3)
+            .limit_other_args(4)
+            .limit_unknown_options(5);
+
+        let empty = spec.clone_empty();
+        assert_eq!(0, empty.options.len());
+        assert_eq!(0, empty.dependencies.len());
+        assert_eq!(0, empty.exclusive_groups.len());
+        assert_eq!(true, empty.is_flag(OptFlags::OptionsEverywhere));
+        assert_eq!(3, empty.option_limit);
+        assert_eq!(4, empty.other_limit);
+        assert_eq!(5, empty.unknown_limit);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn t_parsed_output_470() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .getopt(["-f", "/tmp/foo", "-f", "bar/baz.txt"]);
+
+        assert_eq!(
+            Some(std::path::PathBuf::from("bar/baz.txt")),
+            parsed.option_value_to_path("file")
+        );
+        assert_eq!(None, parsed.option_value_to_path("not-at-all"));
+    }
+
+    #[test]
+    fn t_parsed_output_480() {
+        let specs = OptSpecs::new()
+            .flag(OptFlags::DisallowLongOptions)
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None);
+
+        let parsed = specs.getopt(["--help", "-h", "--foo=bar", "-x", "--", "-h"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!("h", parsed.options[0].name);
+        assert_eq!(vec!["--help", "--foo=bar", "-h"], parsed.other);
+        assert_eq!(vec!["x"], parsed.unknown);
+
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::DisallowLongOptions)
+            .option("help", "h", OptValue::None)
+            .getopt(["-h", "--help", "-h"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["--help", "-h"], parsed.other);
+    }
+
+    #[test]
+    fn t_parsed_output_490() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::DisallowShortOptions)
+            .flag(OptFlags::OptionsEverywhere)
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None)
+            .getopt(["-h", "--help", "-abc", "--foo", "-5"]);
+
+        assert_eq!(1, parsed.options.len());
+        assert_eq!("help", parsed.options[0].name);
+        assert_eq!(vec!["-h", "-abc", "-5"], parsed.other);
+        assert_eq!(vec!["foo"], parsed.unknown);
+    }
+
+    #[test]
+    fn t_parsed_output_500() {
+        let parsed = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("help", "h", OptValue::None)
+            .getopt(["-v", "-f1", "-v", "--file=2", "-h"]);
+
+        assert_eq!(vec!["file", "help", "verbose"], parsed.all_option_ids());
+        assert_eq!(0, OptSpecs::new().getopt(["foo"]).all_option_ids().len());
+    }
+
+    #[test]
+    fn t_parsed_output_510() {
+        let specs = OptSpecs::new()
+            .option("count", "c", OptValue::Required)
+            .option("level", "l", OptValue::Optional);
+
+        let parsed = specs.getopt(["-c1", "-c", "20", "-l"]);
+        assert_eq!(20, parsed.option_value_parse_or("count", 5));
+        assert_eq!(5, parsed.option_value_parse_or("level", 5));
+        assert_eq!(5, parsed.option_value_parse_or("not-at-all", 5));
+
+        let parsed = specs.getopt(["-cx", "-l3"]);
+        assert_eq!(5, parsed.option_value_parse_or("count", 5));
+        assert_eq!(3.0, parsed.option_value_parse_or("level", 1.5));
+    }
+
+    #[test]
+    fn t_create_optspecs_140() {
+        let spec = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option_list([
+                ("help", "help", OptValue::None),
+                ("file", "f", OptValue::Required),
+                ("file", "file", OptValue::Required),
+            ]);
+
+        assert_eq!(4, spec.options.len());
+        assert_eq!("help", spec.options[1].name);
+        assert_eq!("f", spec.options[2].name);
+        assert_eq!("file", spec.options[3].id);
+        assert_eq!(OptValue::Required, spec.options[3].value_type);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_150() {
+        OptSpecs::new().option_list([("help", "h", OptValue::None), ("help", "h", OptValue::None)]);
+    }
+
+    #[test]
+    fn t_parsed_output_520() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::RequiredNonEmpty)
+            .option("file", "file", OptValue::RequiredNonEmpty)
+            .option("debug", "d", OptValue::Required);
+
+        let parsed = specs.getopt(["-f", "", "-d1", "--file=", "-d"]);
+        assert_eq!("f", parsed.required_value_missing_first().unwrap().name);
+        assert_eq!("d", parsed.required_value_missing_last().unwrap().name);
+
+        let parsed = specs.getopt(["-f1", "-d", ""]);
+        assert_eq!(None, parsed.required_value_missing_first());
+        assert_eq!(None, parsed.required_value_missing_last());
+    }
+
+    #[test]
+    fn t_create_optspecs_160() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .with_version_info("1.2.3");
+
+        assert_eq!(Some(String::from("1.2.3")), specs.version);
+        assert_eq!(3, specs.options.len());
+        assert_eq!("V", specs.options[1].name);
+        assert_eq!("version", specs.options[2].name);
+        assert_eq!("version", specs.options[2].id);
+
+        let parsed = specs.getopt(["-V", "--version"]);
+        assert_eq!(2, parsed.options_all("version").count());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn t_parsed_output_530() {
+        let specs = OptSpecs::new().with_version_info("1.2.3");
+        assert_eq!(
+            true,
+            specs.getopt(["-V"]).print_version_if_requested(&specs)
+        );
+        assert_eq!(
+            false,
+            specs.getopt(["foo"]).print_version_if_requested(&specs)
+        );
+
+        let specs = OptSpecs::new().option("version", "V", OptValue::None);
+        assert_eq!(
+            false,
+            specs.getopt(["-V"]).print_version_if_requested(&specs)
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_540() {
+        let parsed = OptSpecs::new()
+            .option("name", "name", OptValue::Required)
+            .option("debug", "d", OptValue::Optional)
+            .getopt(["--name", "  foo bar\t", "-d"]);
+
+        assert_eq!(Some("foo bar"), parsed.option_value_trimmed("name"));
+        assert_eq!(None, parsed.option_value_trimmed("debug"));
+        assert_eq!(None, parsed.option_value_trimmed("not-at-all"));
+    }
+
+    #[test]
+    fn t_parsed_output_550() {
+        let parsed = OptSpecs::new().getopt(["foo", "bar", ""]);
+
+        assert_eq!(true, parsed.other_contains("foo"));
+        assert_eq!(true, parsed.other_contains(""));
+        assert_eq!(false, parsed.other_contains("baz"));
+        assert_eq!(true, parsed.other_contains_any(&["baz", "bar"]));
+        assert_eq!(false, parsed.other_contains_any(&["baz", "fo"]));
+        assert_eq!(false, parsed.other_contains_any(&[]));
+    }
+
+    #[test]
+    fn t_parsed_output_560() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("file", "input", OptValue::Required)
+            .option("help", "h", OptValue::None);
+
+        let parsed = specs
+            .getopt(["-f1", "--input=2", "-h", "--file", "3"])
+            .normalize_names(&specs);
+        let names: Vec<&str> = parsed.options.iter().map(|o| o.name.as_str()).collect();
+        assert_eq!(vec!["file", "file", "h", "file"], names);
+        assert_eq!("2", parsed.options[1].value.clone().unwrap());
+    }
+
+    #[test]
+    fn t_parsed_output_570() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required);
+
+        let mut parsed = specs.getopt(["-f1", "--file=2"]);
+        parsed.options.push(Opt {
+            id: String::from("file"),
+            name: String::from("input"),
+            value_required: true,
+            value: Some(String::from("3")),
+            source_index: 2,
+            no_prefix: false,
+        });
+
+        assert_eq!(3, parsed.options_all("file").count());
+        let v: Vec<&Opt> = parsed.option_defined_in_specs("file", &specs).collect();
+        assert_eq!(2, v.len());
+        assert_eq!("f", v[0].name);
+        assert_eq!("file", v[1].name);
+    }
+
+    #[test]
+    fn t_create_optspecs_170() {
+        let spec = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option_in_category(&["output", "format"], "json", "json", OptValue::None)
+            .option_in_category(&["output"], "file", "o", OptValue::Required)
+            .option_in_category(&["output", "format"], "xml", "xml", OptValue::None)
+            .option("help", "help", OptValue::None);
+
+        assert_eq!(vec!["output", "format"], spec.options[1].category);
+
+        let groups: Vec<(Vec<&str>, Vec<&str>)> = spec
+            .iter_specs_by_category()
+            .map(|(path, specs)| (path, specs.iter().map(|s| s.name.as_str()).collect()))
+            .collect();
+        assert_eq!(
+            vec![
+                (vec![], vec!["h", "help"]),
+                (vec!["output", "format"], vec!["json", "xml"]),
+                (vec!["output"], vec!["o"]),
+            ],
+            groups
+        );
+
+        let (_, specs) = spec.iter_specs_by_category().nth(2).unwrap();
+        assert_eq!("file", specs[0].id);
+        assert_eq!(OptValue::Required, specs[0].value_type);
+
+        assert_eq!(0, OptSpecs::new().iter_specs_by_category().count());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn t_parsed_output_580() {
+        let parsed = OptSpecs::new()
+            .flag(OptFlags::OptionsEverywhere)
+            .option("file", "file", OptValue::Required)
+            .option("verbose", "v", OptValue::Optional)
+            .getopt(["--file=123", "-v", "foo", "-a"]);
+
+        let mut out: Vec<u8> = Vec::new();
+        parsed.debug_print_to(&mut out).unwrap();
+        assert_eq!(
+            "1 [O] --file (file) = \"123\"\n\
+             2 [O] -v (verbose)\n\
+             3 [+] \"foo\"\n\
+             4 [U] -a\n",
+            String::from_utf8(out).unwrap()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_590() {
+        let specs = OptSpecs::new().option("help", "h", OptValue::None);
+        assert_eq!(
+            specs.getopt(["-h", "-x", "foo"]),
+            specs.getopt(["-h", "-x", "foo"]).into_owned()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_600() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("help-long", "help", OptValue::None)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["--help", "-v"]);
+
+        assert_eq!(true, parsed.option_exists_any(&["help", "help-long"]));
+        assert_eq!(false, parsed.option_exists_any(&["help", "x"]));
+        assert_eq!(false, parsed.option_exists_any(&[]));
+        assert_eq!(true, parsed.option_exists_all(&["help-long", "verbose"]));
+        assert_eq!(false, parsed.option_exists_all(&["help", "verbose"]));
+        assert_eq!(true, parsed.option_exists_all(&[]));
+    }
+
+    #[test]
+    fn t_create_optspecs_180() {
+        let spec = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("file", "f", OptValue::RequiredNonEmpty)
+            .option("help", "help", OptValue::None)
+            .option("file", "file", OptValue::RequiredNonEmpty)
+            .option("verbose", "verbose", OptValue::Optional)
+            .option("debug", "d", OptValue::OptionalNonEmpty);
+
+        assert_eq!(
+            "[-h|--help] [-f|--file <VALUE>] [--verbose[=VALUE]] [-d[VALUE]]",
+            spec.summary_string()
+        );
+        assert_eq!("", OptSpecs::new().summary_string());
+    }
+
+    #[test]
+    fn t_parsed_output_610() {
+        let parsed = OptSpecs::new()
+            .option("color", "color", OptValue::Optional)
+            .option("debug", "debug", OptValue::Required)
+            .option("quiet", "quiet", OptValue::Required)
+            .option("help", "h", OptValue::None)
+            .getopt(["--color=YES", "--debug", "Off", "--quiet=maybe", "-h"]);
+
+        assert_eq!(Some(Ok(true)), parsed.option_value_as_bool("color"));
+        assert_eq!(Some(Ok(false)), parsed.option_value_as_bool("debug"));
+        assert_eq!(Some(Err("maybe")), parsed.option_value_as_bool("quiet"));
+        assert_eq!(None, parsed.option_value_as_bool("help"));
+        assert_eq!(None, parsed.option_value_as_bool("nothing"));
+    }
+
+    #[test]
+    fn t_parsed_output_620() {
+        let parsed = OptSpecs::new()
+            .option("include", "include", OptValue::Required)
+            .option("path", "path", OptValue::Required)
+            .getopt(["--include=x", "--include=foo,bar,baz", "--path=/a:/b"]);
+
+        assert_eq!(
+            Some(vec!["foo", "bar", "baz"]),
+            parsed.option_value_as_list("include")
+        );
+        assert_eq!(Some(vec!["/a:/b"]), parsed.option_value_as_list("path"));
+        assert_eq!(
+            Some(vec!["/a", "/b"]),
+            parsed.option_value_split("path", ':')
+        );
+        assert_eq!(None, parsed.option_value_as_list("nothing"));
+    }
+
+    #[test]
+    fn t_parsed_output_630() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["--file=a", "-v", "-fb", "--file", "c", "-v"]);
+
+        assert_eq!(vec!["file", "v", "f"], parsed.unique_option_names());
+        assert_eq!(
+            0,
+            OptSpecs::new().getopt(["foo"]).unique_option_names().len()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_640() {
+        let specs = OptSpecs::new()
+            .option("format", "output-json", OptValue::None)
+            .option("format", "output-text", OptValue::None)
+            .option("output", "o", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .option_depends_on("verbose", "output")
+            .option_requires_id("output-json", "output");
+
+        assert_eq!(
+            vec![
+                DependencyError {
+                    dependent: "verbose".to_string(),
+                    dependent_name: None,
+                    required: "output".to_string(),
+                },
+                DependencyError {
+                    dependent: "format".to_string(),
+                    dependent_name: Some("--output-json".to_string()),
+                    required: "output".to_string(),
+                },
+            ],
+            specs
+                .getopt(["--output-json", "-v"])
+                .check_dependencies(&specs)
+        );
+        let e = specs
+            .getopt(["--output-json", "-v"])
+            .check_dependencies(&specs);
+        assert_eq!(
+            "Option \"verbose\" requires option \"output\".",
+            e[0].to_string()
+        );
+        assert_eq!(
+            "Option \"--output-json\" requires option \"output\".",
+            e[1].to_string()
+        );
+        assert_eq!(
+            0,
+            specs
+                .getopt(["--output-text"])
+                .check_dependencies(&specs)
+                .len()
+        );
+        assert_eq!(
+            0,
+            specs
+                .getopt(["--output-json", "-ofile"])
+                .check_dependencies(&specs)
+                .len()
+        );
+    }
 
-        assert_eq!("foo", parsed.other[0]);
-        assert_eq!("bar", parsed.other[1]);
+    #[test]
+    fn t_parsed_output_650() {
+        let mut parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .getopt(["-h", "foo"])
+            .with_extra_other(["bar", "baz"]);
+
+        assert_eq!(vec!["foo", "bar", "baz"], parsed.other);
+
+        parsed.extend_other(vec![String::from("qux")]);
+        assert_eq!(vec!["foo", "bar", "baz", "qux"], parsed.other);
+        assert_eq!(1, parsed.options.len());
     }
 
     #[test]
-    fn t_parsed_output_020() {
+    fn t_parsed_output_660() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option_no_prefix("build", "build", OptValue::None)
+            .option_no_prefix("target", "target", OptValue::Required)
+            .option_no_prefix("mode", "m", OptValue::Optional);
+
+        let parsed = specs.getopt(["build", "-h", "target", "x86", "m", "foo", "-h"]);
+
+        assert_eq!(4, parsed.options.len());
+        assert_eq!("build", parsed.options[0].id);
+        assert_eq!("build", parsed.options[0].name);
+        assert_eq!(None, parsed.options[0].value);
+        assert_eq!("help", parsed.options[1].id);
+        assert_eq!("target", parsed.options[2].id);
+        assert_eq!(true, parsed.options[2].value_required);
+        assert_eq!(Some("x86"), parsed.options[2].value.as_deref());
+        assert_eq!(2, parsed.options[2].source_index);
+        assert_eq!("mode", parsed.options[3].id);
+        assert_eq!(None, parsed.options[3].value);
+        assert_eq!(vec!["foo", "-h"], parsed.other);
+
+        let parsed = specs.getopt(["--build", "-m", "--", "build"]);
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(vec!["build", "m"], parsed.unknown);
+        assert_eq!(vec!["build"], parsed.other);
+
+        let parsed = specs
+            .clone_empty()
+            .flag(OptFlags::PrefixMatchLongOptions)
+            .option_no_prefix("build", "build", OptValue::None)
+            .getopt(["--bu"]);
+        assert_eq!(vec!["bu"], parsed.unknown);
+
+        assert_eq!("[-h] [build] [target <VALUE>] [m]", specs.summary_string());
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_190() {
+        let _ = OptSpecs::new().option_no_prefix("x", "-x", OptValue::None);
+    }
+
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_200() {
+        let _ = OptSpecs::new()
+            .option("help", "help", OptValue::None)
+            .option_no_prefix("help", "help", OptValue::None);
+    }
+
+    #[test]
+    fn t_parsed_output_670() {
+        let specs = OptSpecs::new();
+
+        assert_eq!(Ok(()), specs.getopt(["a"]).check_at_most_one_other());
+        assert_eq!(
+            Err(ExtraArgsError {
+                expected: 1,
+                found: 3,
+                extra: vec!["b".to_string(), "c".to_string()],
+            }),
+            specs.getopt(["a", "b", "c"]).check_at_most_one_other()
+        );
+
+        assert_eq!(Ok(()), specs.getopt(["a", "b"]).check_exactly_n_other(2));
+        let err = specs.getopt(["a"]).check_exactly_n_other(2).unwrap_err();
+        assert_eq!(0, err.extra.len());
+        assert_eq!("Too few arguments (2 expected, 1 given).", err.to_string());
+
+        assert_eq!(Ok(()), specs.getopt(["a", "b"]).check_at_least_n_other(1));
+        assert_eq!(
+            Err(ExtraArgsError {
+                expected: 2,
+                found: 0,
+                extra: Vec::new(),
+            }),
+            specs.getopt([""; 0]).check_at_least_n_other(2)
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_680() {
         let parsed = OptSpecs::new()
-            .limit_options(1)
-            .limit_other_args(2)
+            .option("address", "address", OptValue::Required)
+            .option("mask", "mask", OptValue::Required)
+            .option("bits", "bits", OptValue::Required)
+            .option("offset", "offset", OptValue::Required)
             .option("help", "h", OptValue::None)
-            .getopt(["-h", "foo", "-h"]);
+            .getopt([
+                "--address=0x1F00",
+                "--mask=0o755",
+                "--bits=0B101",
+                "--offset=-0xff",
+                "-h",
+            ]);
 
-        assert_eq!("h", parsed.options_first("help").unwrap().name);
-        assert_eq!(2, parsed.other.len());
-        assert_eq!("foo", parsed.other[0]);
-        assert_eq!("-h", parsed.other[1]);
+        assert_eq!(Some(Ok(0x1f00)), parsed.option_value_base("address", 16));
+        assert_eq!(Some(Ok(0o755)), parsed.option_value_base("mask", 8));
+        assert_eq!(Some(Ok(0b101)), parsed.option_value_base("bits", 2));
+        assert_eq!(Some(Ok(-255)), parsed.option_value_base("offset", 16));
+        assert_eq!(
+            true,
+            parsed.option_value_base("address", 10).unwrap().is_err()
+        );
+        assert_eq!(true, parsed.option_value_base("bits", 8).unwrap().is_err());
+        assert_eq!(None, parsed.option_value_base("h", 10));
+
+        let parsed = OptSpecs::new()
+            .option("n", "n", OptValue::Required)
+            .getopt(["-n", "0b1"]);
+        assert_eq!(Some(Ok(0xb1)), parsed.option_value_base("n", 16));
+
+        let specs = OptSpecs::new().option("n", "n", OptValue::Required);
+        for value in ["+-5", "-+5", "--5", "0x-1F", "0x+1F", "-0x-1F"] {
+            let parsed = specs.getopt(["-n", value]);
+            assert_eq!(true, parsed.option_value_base("n", 16).unwrap().is_err());
+        }
+        let parsed = specs.getopt(["-n", "+-5"]);
+        assert_eq!(true, parsed.option_value_base("n", 10).unwrap().is_err());
     }
 
     #[test]
-    fn t_parsed_output_030() {
+    #[cfg(feature = "base64")]
+    fn t_parsed_output_690() {
         let parsed = OptSpecs::new()
-            .flag(OptFlags::OptionsEverywhere)
+            .option("key", "key", OptValue::Required)
+            .option("bad", "bad", OptValue::Required)
+            .getopt(["--key=3q2+7w==", "--bad=3q2*"]);
+
+        assert_eq!(
+            Some(Ok(vec![0xde, 0xad, 0xbe, 0xef])),
+            parsed.option_value_bytes("key")
+        );
+        assert_eq!(
+            Some(Err(Base64DecodeError::InvalidByte(3, b'*'))),
+            parsed.option_value_bytes("bad")
+        );
+        assert_eq!(None, parsed.option_value_bytes("nothing"));
+    }
+
+    #[test]
+    fn t_create_optspecs_210() {
+        let specs = OptSpecs::from_help_string(
+            "Usage: prog [options] [--] [args]\n\
+             \n\
+             -f, --file FILE     file to process\n\
+             -c, --color[=WHEN]  use colors\n\
+             -v, --verbose       enable verbosity\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            "[-f|--file <VALUE>] [-c|--color[=VALUE]] [-v|--verbose]",
+            specs.summary_string()
+        );
+
+        let parsed = specs.getopt(["-vfa", "--color", "x"]);
+        assert_eq!("verbose", parsed.options[0].id);
+        assert_eq!("file", parsed.options[1].id);
+        assert_eq!(Some("a"), parsed.options[1].value.as_deref());
+        assert_eq!("color", parsed.options[2].id);
+        assert_eq!(None, parsed.options[2].value);
+        assert_eq!(vec!["x"], parsed.other);
+
+        let specs = OptSpecs::from_help_string(
+            "  -v, --verbose  verbose\n  --   end of options\n  - item\n",
+        )
+        .unwrap();
+        assert_eq!(2, specs.options.len());
+
+        assert_eq!(
+            Err(OptionSpecError::DuplicateName("v".to_string())),
+            OptSpecs::from_help_string("-v  verbose\n-v  version\n")
+        );
+        assert_eq!(
+            Err(OptionSpecError::InvalidName("-foo".to_string())),
+            OptSpecs::from_help_string("---foo  foo\n")
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_700() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .getopt(["--zeta", "-zha", "--alpha", "--beta", "-x", "--alpha"]);
+
+        assert_eq!(vec!["alpha", "beta", "zeta"], parsed.unique_unknown_long());
+        assert_eq!(vec!["a", "x", "z"], parsed.unique_unknown_short());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn t_parsed_output_710() {
+        std::env::set_var("JUST_GETOPT_T710_FILE", "env.txt");
+        std::env::set_var("JUST_GETOPT_T710_QUIET", "whatever");
+        std::env::set_var("JUST_GETOPT_T710_EMPTY", "");
+        std::env::remove_var("JUST_GETOPT_T710_UNSET");
+
+        let specs = OptSpecs::new()
+            .option_parse_env("file", "file", OptValue::Required, "JUST_GETOPT_T710_FILE")
+            .option_parse_env("quiet", "q", OptValue::None, "JUST_GETOPT_T710_QUIET")
+            .option_parse_env(
+                "empty",
+                "empty",
+                OptValue::RequiredNonEmpty,
+                "JUST_GETOPT_T710_EMPTY",
+            )
+            .option_parse_env("unset", "unset", OptValue::None, "JUST_GETOPT_T710_UNSET");
+
+        let parsed = specs.getopt(["--file=cli.txt", "foo"]);
+        assert_eq!(4, parsed.options.len());
+        assert_eq!("file", parsed.options[0].id);
+        assert_eq!(Some("env.txt"), parsed.options[0].value.as_deref());
+        assert_eq!(usize::MAX, parsed.options[0].source_index);
+        assert_eq!("quiet", parsed.options[1].id);
+        assert_eq!(None, parsed.options[1].value);
+        assert_eq!("empty", parsed.options[2].id);
+        assert_eq!(true, parsed.options[2].value_required);
+        assert_eq!(None, parsed.options[2].value);
+        assert_eq!(0, parsed.options[3].source_index);
+        assert_eq!(
+            Some(&"cli.txt".to_string()),
+            parsed.options_value_last("file")
+        );
+        assert_eq!(false, parsed.option_exists("unset"));
+        assert_eq!(vec!["foo"], parsed.other);
+    }
+
+    #[test]
+    fn t_parsed_output_720() {
+        let specs = OptSpecs::new().option("help", "h", OptValue::None);
+
+        let result = specs.getopt(["-h", "foo"]).validate_with(|_| Vec::new());
+        assert_eq!(true, result.unwrap().option_exists("help"));
+
+        let result = specs.getopt(["foo"]).validate_with(|args| {
+            let mut messages = Vec::new();
+            if !args.option_exists("help") {
+                messages.push("No help.".to_string());
+            }
+            if args.other.len() != 2 {
+                messages.push("Two arguments needed.".to_string());
+            }
+            messages
+        });
+        assert_eq!(
+            Err(vec![
+                "No help.".to_string(),
+                "Two arguments needed.".to_string()
+            ]),
+            result
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_730() {
+        let parsed = OptSpecs::new()
+            .option("transform", "t", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["-t1", "-v", "-t2", "-t3"]);
+
+        let options = parsed.options_n("transform", 3).unwrap();
+        assert_eq!(3, options.len());
+        assert_eq!(Some("1"), options[0].value.as_deref());
+        assert_eq!(Some("3"), options[2].value.as_deref());
+
+        let err = parsed.options_n("verbose", 2).unwrap_err();
+        assert_eq!(
+            ArityError {
+                id: "verbose".to_string(),
+                expected: 2,
+                found: 1,
+            },
+            err
+        );
+        assert_eq!(
+            "Option \"verbose\" must be given 2 time(s), found 1.",
+            err.to_string()
+        );
+        assert_eq!(Ok(Vec::new()), parsed.options_n("nothing", 0));
+    }
+
+    #[test]
+    fn t_parsed_output_740() {
+        let parsed = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .option("verbose", "verbose", OptValue::None)
             .option("help", "h", OptValue::None)
+            .limit_options(4)
+            .getopt(["-vv", "--verbose", "-h", "-v"]);
+
+        assert_eq!(3, parsed.option_count_id("verbose"));
+        assert_eq!(1, parsed.option_count_id("help"));
+        assert_eq!(0, parsed.option_count_id("nothing"));
+    }
+
+    #[test]
+    fn t_parsed_output_750() {
+        let specs = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
             .option("help", "help", OptValue::None)
+            .flag(OptFlags::StopAtUnknown);
+
+        let parsed = specs.getopt(["-v", "--foo=bar", "-v", "baz"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["foo"], parsed.unknown);
+        assert_eq!(vec!["--foo=bar", "-v", "baz"], parsed.other);
+
+        let parsed = specs.getopt(["-v", "-vxv", "--help"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(0, parsed.options[0].source_index);
+        assert_eq!(vec!["x"], parsed.unknown);
+        assert_eq!(vec!["-vxv", "--help"], parsed.other);
+
+        let parsed = specs.getopt(["-vx"]);
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(vec!["x"], parsed.unknown);
+        assert_eq!(vec!["-vx"], parsed.other);
+
+        let parsed = specs.getopt(["--help=x", "-v"]);
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(vec!["help="], parsed.unknown);
+        assert_eq!(vec!["--help=x", "-v"], parsed.other);
+
+        let parsed = specs.getopt(["-v", "--help", "--", "-x"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(0, parsed.unknown.len());
+        assert_eq!(vec!["-x"], parsed.other);
+    }
+
+    #[test]
+    #[cfg(feature = "toml")]
+    fn t_parsed_output_760() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .option("a b", "ab", OptValue::Optional)
+            .getopt(["-fa.txt", "-v", "--ab=\"x\"\\\n", "-fb.txt", "foo", "--bar"]);
+
+        assert_eq!(
+            "[options]\n\
+             file = [\"a.txt\", \"b.txt\"]\n\
+             verbose = []\n\
+             \"a b\" = [\"\\\"x\\\"\\\\\\n\"]\n\
+             \n\
+             [other]\n\
+             args = [\"foo\", \"--bar\"]\n",
+            parsed.to_structured_toml()
+        );
+        assert_eq!(
+            "[options]\n\n[other]\nargs = [\"\\u0001\"]\n",
+            OptSpecs::new().getopt(["\u{1}"]).to_structured_toml()
+        );
+        assert_eq!(
+            "[options]\nother = [\"x\"]\n\n[other]\nargs = [\"y\"]\n",
+            OptSpecs::new()
+                .option("other", "other", OptValue::Required)
+                .getopt(["--other=x", "y"])
+                .to_structured_toml()
+        );
+    }
+
+    #[test]
+    fn t_parsed_output_770() {
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .getopt(["--zeta", "-zh", "--alpha", "-x"]);
+
+        let long: Vec<&String> = parsed.unknown_long().collect();
+        assert_eq!(vec!["zeta", "alpha"], long);
+        let short: Vec<&String> = parsed.unknown_short().rev().collect();
+        assert_eq!(vec!["x", "z"], short);
+    }
+
+    #[test]
+    fn t_parsed_output_780() {
+        let specs = OptSpecs::new()
             .option("file", "f", OptValue::Required)
             .option("file", "file", OptValue::Required)
-            .getopt(["-h", "foo", "--help", "--file=123", "bar", "--file", "456"]);
+            .option("level", "level", OptValue::Optional)
+            .apply_defaults(&[("file", "x.txt"), ("level", "1"), ("color", "no")])
+            .apply_defaults(&[("level", "2")]);
 
-        assert_eq!("h", parsed.options_first("help").unwrap().name);
-        assert_eq!("help", parsed.options_last("help").unwrap().name);
+        let parsed = specs.getopt(["--level", "foo"]);
+        assert_eq!(3, parsed.options.len());
+        assert_eq!("level", parsed.options[0].id);
+        assert_eq!(None, parsed.options[0].value);
+        assert_eq!("file", parsed.options[1].id);
+        assert_eq!("f", parsed.options[1].name);
+        assert_eq!(true, parsed.options[1].value_required);
+        assert_eq!(Some("x.txt"), parsed.options[1].value.as_deref());
+        assert_eq!(usize::MAX, parsed.options[1].source_index);
+        assert_eq!("color", parsed.options[2].id);
+        assert_eq!("color", parsed.options[2].name);
+        assert_eq!(Some("no"), parsed.options[2].value.as_deref());
+
+        let parsed = specs.getopt(["--file=y.txt"]);
         assert_eq!(
-            "123",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
+            Some(&"y.txt".to_string()),
+            parsed.options_value_last("file")
         );
+        assert_eq!(Some(&"2".to_string()), parsed.options_value_last("level"));
+    }
+
+    #[test]
+    fn t_parsed_output_790() {
+        let specs = OptSpecs::new().option("file", "f", OptValue::Required);
+        let copy = specs.clone().option("help", "h", OptValue::None);
+        assert_eq!(1, specs.options.len());
+        assert_eq!(2, copy.options.len());
+
+        let parsed = specs.getopt(["-fa", "foo"]);
+        let mut snapshot = parsed.clone();
+        snapshot.other.clear();
+        snapshot.options[0].value = None;
+        assert_eq!(vec!["foo"], parsed.other);
+        assert_eq!(Some(&"a".to_string()), parsed.options_value_last("file"));
+        assert_eq!(parsed.options[0].id, snapshot.options[0].clone().id);
+    }
+
+    #[test]
+    fn t_parsed_output_800() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::None)
+            .option("file", "file", OptValue::None)
+            .option("euro", "€", OptValue::None)
+            .getopt(["-f", "--file", "-€"]);
+
+        assert_eq!(true, parsed.options[0].is_short());
+        assert_eq!(false, parsed.options[0].is_long());
+        assert_eq!(false, parsed.options[1].is_short());
+        assert_eq!(true, parsed.options[1].is_long());
+        assert_eq!(true, parsed.options[2].is_short());
+        assert_eq!(false, parsed.options[2].is_long());
+    }
+
+    #[test]
+    fn t_parsed_output_810() {
+        let parsed = OptSpecs::new()
+            .option("type", "t", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .flag(OptFlags::OptionsEverywhere)
+            .getopt(["-t", "a", "file1", "-v", "-tb", "file2", "-tc"]);
+
+        let pairs: Vec<(Option<&str>, Option<&str>)> = parsed
+            .options_zip_other("type")
+            .map(|(opt, other)| (opt.value.as_deref(), other))
+            .collect();
         assert_eq!(
-            "456",
-            parsed.options_last("file").unwrap().value.clone().unwrap()
+            vec![
+                (Some("a"), Some("file1")),
+                (Some("b"), Some("file2")),
+                (Some("c"), None),
+            ],
+            pairs
         );
-        assert_eq!("foo", parsed.other[0]);
-        assert_eq!("bar", parsed.other[1]);
+        assert_eq!(0, parsed.options_zip_other("nothing").count());
     }
 
     #[test]
-    fn t_parsed_output_040() {
+    fn t_parsed_output_820() {
         let parsed = OptSpecs::new()
-            .option("debug", "d", OptValue::Optional)
-            .option("verbose", "verbose", OptValue::Optional)
-            .getopt(["-d1", "-d", "--verbose", "--verbose=123"]);
+            .option("file", "f", OptValue::Optional)
+            .getopt(["-fa", "-f"]);
+
+        assert_eq!(true, parsed.options[0].has_value());
+        assert_eq!(Some("a"), parsed.options[0].value_str());
+        assert_eq!(false, parsed.options[1].has_value());
+        assert_eq!(None, parsed.options[1].value_str());
+    }
+
+    #[test]
+    fn t_parsed_output_830() {
+        let specs = OptSpecs::new().option("file", "f", OptValue::Required);
+
+        assert_eq!(true, specs.getopt(["-fa"]).is_fully_parsed());
+        assert_eq!(false, specs.getopt(["-f"]).is_fully_parsed());
+        assert_eq!(false, specs.getopt(["-x"]).is_fully_parsed());
+
+        let mut parsed = specs.getopt(["-fa", "foo"]);
+        assert_eq!(false, parsed.is_fully_parsed());
+        parsed.other.remove(0);
+        assert_eq!(true, parsed.is_fully_parsed());
+    }
+
+    #[test]
+    fn t_parsed_output_840() {
+        let parsed = OptSpecs::new()
+            .option("opt-1", "a", OptValue::Optional)
+            .option("opt-2", "b", OptValue::None)
+            .getopt(["-ax", "-b", "-a", "foo"]);
+
+        let mut options = Vec::new();
+        let mut values = Vec::new();
+        for n in 1..=2 {
+            options.extend(parsed.options_all(&alloc::format!("opt-{}", n)));
+            values.extend(parsed.options_value_all(&alloc::format!("opt-{}", n)));
+        }
+        assert_eq!(3, options.len());
+        assert_eq!(vec!["x"], values);
+
+        let no_value = {
+            let id = String::from("opt-1");
+            parsed.options_not_having_value(&id)
+        };
+        assert_eq!(1, no_value.count());
+    }
 
+    #[test]
+    fn t_parsed_output_850() {
+        let specs = OptSpecs::new().option("help", "h", OptValue::None);
+
+        let parsed = specs.getopt([""; 0]);
+        assert_eq!(true, parsed.is_empty());
+        assert_eq!(0, parsed.len());
+
+        let parsed = specs.getopt(["-hx", "--", "foo", "bar"]);
+        assert_eq!(false, parsed.is_empty());
+        assert_eq!(4, parsed.len());
+    }
+
+    #[test]
+    fn t_create_optspecs_220() {
+        let rows = vec![
+            ("help".to_string(), "h".to_string(), OptValue::None),
+            ("help".to_string(), "help".to_string(), OptValue::None),
+            ("file".to_string(), "file".to_string(), OptValue::Required),
+        ];
+        let specs: OptSpecs = rows.into_iter().collect();
+
+        assert_eq!(3, specs.options.len());
+        assert_eq!("[-h|--help] [--file <VALUE>]", specs.summary_string());
         assert_eq!(
-            "1",
-            parsed
-                .options_first("debug")
-                .unwrap()
-                .value
-                .clone()
-                .unwrap()
+            OptSpecs::new(),
+            Vec::<(String, String, OptValue)>::new()
+                .into_iter()
+                .collect()
         );
-        assert_eq!(None, parsed.options_last("debug").unwrap().value);
-        assert_eq!(false, parsed.options_last("debug").unwrap().value_required);
+    }
 
-        assert_eq!(None, parsed.options_first("verbose").unwrap().value);
+    #[test]
+    fn t_parsed_output_860() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["-v", "-fa", "--file=b", "-v"]);
+
+        let groups = parsed.group_by_id();
         assert_eq!(
-            "123",
-            parsed
-                .options_last("verbose")
-                .unwrap()
-                .value
-                .clone()
-                .unwrap()
+            vec!["file", "verbose"],
+            groups.keys().copied().collect::<Vec<_>>()
         );
+        assert_eq!(2, groups["file"].len());
+        assert_eq!("f", groups["file"][0].name);
+        assert_eq!("file", groups["file"][1].name);
+        assert_eq!(2, groups["verbose"].len());
+        assert_eq!(0, OptSpecs::new().getopt(["foo"]).group_by_id().len());
+    }
+
+    #[test]
+    fn t_parsed_output_870() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("verbose", "verbose", OptValue::None)
+            .getopt(["-fa", "--verbose", "-f", ""]);
+
         assert_eq!(
-            false,
-            parsed.options_last("verbose").unwrap().value_required
+            vec![("f", "a"), ("verbose", ""), ("f", "")],
+            parsed.option_value_pairs()
         );
     }
 
     #[test]
-    fn t_parsed_output_050() {
+    fn t_parsed_output_880() {
         let parsed = OptSpecs::new()
-            .option("debug", "d", OptValue::Optional)
-            .getopt(["-abcd", "-adbc"]);
+            .option("verbose", "v", OptValue::None)
+            .option("file", "f", OptValue::Required)
+            .option("help", "h", OptValue::None)
+            .getopt(["-v", "-fa", "-v", "-h", "-fb"]);
 
-        assert_eq!(None, parsed.options_first("debug").unwrap().value);
         assert_eq!(
-            "bc",
-            parsed.options_last("debug").unwrap().value.clone().unwrap()
+            vec!["verbose", "file", "help"],
+            parsed.unique_ids().collect::<Vec<&str>>()
+        );
+        assert_eq!(Some("help"), parsed.unique_ids().next_back());
+        assert_eq!(0, OptSpecs::new().getopt(["foo"]).unique_ids().count());
+    }
+
+    #[test]
+    fn t_parsed_output_890() {
+        let specs = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .option("level", "level", OptValue::Optional)
+            .option("help", "help", OptValue::None)
+            .long_value_separator(':');
+
+        let parsed = specs.getopt(["--file:a=b:c", "--level:", "--help:x", "--level=2"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(Some("a=b:c"), parsed.options[0].value.as_deref());
+        assert_eq!(Some(""), parsed.options[1].value.as_deref());
+        assert_eq!(vec!["help:", "level=2"], parsed.unknown);
+
+        let parsed = specs
+            .clone_empty()
+            .option("x", "xx", OptValue::Optional)
+            .getopt(["--xx:1"]);
+        assert_eq!(Some("1"), parsed.options[0].value.as_deref());
+    }
+
+    #[test]
+    fn t_parsed_output_900() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("level", "l", OptValue::Optional)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["-fa", "-v", "-l", "-l2", "-f", ""]);
+
+        assert_eq!(vec!["a", "2", ""], parsed.all_values_flat());
+    }
+
+    #[test]
+    fn t_parsed_output_910() {
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .getopt(["-fa", "--file=b", "-fc"]);
+
+        let short: Vec<&str> = parsed
+            .options_by_name("f")
+            .filter_map(|opt| opt.value.as_deref())
+            .collect();
+        assert_eq!(vec!["a", "c"], short);
+        assert_eq!(1, parsed.options_by_name("file").count());
+        assert_eq!(0, parsed.options_by_name("x").count());
+    }
+
+    #[test]
+    fn t_create_optspecs_240() {
+        let specs = OptSpecs::new()
+            .try_option("help", "h", OptValue::None)
+            .unwrap();
+        assert_eq!(1, specs.options.len());
+
+        assert_eq!(
+            Err(OptionSpecError::EmptyId),
+            OptSpecs::new().try_option("", "h", OptValue::None)
+        );
+        assert_eq!(
+            Err(OptionSpecError::EmptyName),
+            OptSpecs::new().try_option("help", "", OptValue::None)
+        );
+        assert_eq!(
+            Err(OptionSpecError::InvalidName("-".to_string())),
+            OptSpecs::new().try_option("help", "-", OptValue::None)
+        );
+        assert_eq!(
+            Err(OptionSpecError::InvalidName("a=b".to_string())),
+            OptSpecs::new().try_option("help", "a=b", OptValue::None)
+        );
+        assert_eq!(
+            Err(OptionSpecError::DuplicateName("h".to_string())),
+            specs.try_option("help2", "h", OptValue::None)
         );
+    }
 
-        assert_eq!(3, parsed.unknown.len());
-        assert_eq!("a", parsed.unknown[0]);
-        assert_eq!("b", parsed.unknown[1]);
-        assert_eq!("c", parsed.unknown[2]);
+    #[test]
+    #[should_panic(expected = "No duplicates allowed for option's \"name\" (h).")]
+    fn t_create_optspecs_250() {
+        let _ =
+            OptSpecs::new()
+                .option("help", "h", OptValue::None)
+                .option("help", "h", OptValue::None);
     }
 
     #[test]
-    fn t_parsed_output_060() {
+    fn t_parsed_output_920() {
         let parsed = OptSpecs::new()
-            .option("aaa", "bbb", OptValue::None)
-            .option("aaa", "c", OptValue::None)
-            .option("aaa", "d", OptValue::None)
-            .option("aaa", "eee", OptValue::None)
-            .getopt(["--bbb", "-cd", "--eee"]);
+            .option("file", "f", OptValue::Required)
+            .getopt(["-fa", "-fb"]);
 
-        let m: Vec<&Opt> = parsed.options_all("aaa").collect();
-        assert_eq!("bbb", m[0].name);
-        assert_eq!("c", m[1].name);
-        assert_eq!("d", m[2].name);
-        assert_eq!("eee", m[3].name);
+        parsed.assert_has_option("file");
+        parsed.assert_option_value("file", "b");
     }
 
     #[test]
-    fn t_parsed_output_070() {
-        let parsed = OptSpecs::new()
-            .flag(OptFlags::PrefixMatchLongOptions)
-            .option("version", "version", OptValue::None)
-            .option("verbose", "verbose", OptValue::None)
-            .getopt(["--ver", "--verb", "--versi", "--verbose"]);
+    #[should_panic(expected = "Expected option with id 'help' but it was not found.")]
+    fn t_parsed_output_930() {
+        OptSpecs::new().getopt(["foo"]).assert_has_option("help");
+    }
 
-        assert_eq!("ver", parsed.unknown[0]);
-        assert_eq!("verb", parsed.options_first("verbose").unwrap().name);
-        assert_eq!("verbose", parsed.options_last("verbose").unwrap().name);
-        assert_eq!("version", parsed.options_first("version").unwrap().id);
-        assert_eq!("versi", parsed.options_first("version").unwrap().name);
+    #[test]
+    #[should_panic(expected = "Expected value 'a' for option with id 'file' but found 'b'.")]
+    fn t_parsed_output_940() {
+        OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .getopt(["-fb"])
+            .assert_option_value("file", "a");
     }
 
     #[test]
-    fn t_parsed_output_080() {
-        let parsed = OptSpecs::new()
-            // .flag(OptFlags::PrefixMatchLongOptions) Must be commented!
-            .option("version", "version", OptValue::None)
-            .option("verbose", "verbose", OptValue::None)
-            .getopt(["--version", "--ver", "--verb", "--versi", "--verbose"]);
+    fn t_create_optspecs_260() {
+        let specs = OptSpecs::new().option("help", "h", OptValue::None).option(
+            "file",
+            "file",
+            OptValue::Required,
+        );
 
-        assert_eq!("ver", parsed.unknown[0]);
-        assert_eq!("verb", parsed.unknown[1]);
-        assert_eq!("versi", parsed.unknown[2]);
-        assert_eq!("version", parsed.options_first("version").unwrap().name);
-        assert_eq!("verbose", parsed.options_first("verbose").unwrap().name);
+        let list: Vec<(&str, &str, &OptValue)> = specs
+            .iter_specs()
+            .map(|s| (s.id.as_str(), s.name.as_str(), &s.value_type))
+            .collect();
+        assert_eq!(
+            vec![
+                ("help", "h", &OptValue::None),
+                ("file", "file", &OptValue::Required),
+            ],
+            list
+        );
+        assert_eq!(0, OptSpecs::new().iter_specs().count());
     }
 
     #[test]
-    fn t_parsed_output_085() {
+    fn t_parsed_output_950() {
         let parsed = OptSpecs::new()
-            .flag(OptFlags::PrefixMatchLongOptions)
-            .option("foo1", "foo", OptValue::None)
-            .option("foo2", "foo-longer", OptValue::None)
-            .getopt(["--fo", "--foo", "--foo-", "--foo-longer"]);
+            .option("file", "f", OptValue::Required)
+            .getopt(["-fa", "-x", "foo"]);
 
-        assert_eq!("fo", parsed.unknown[0]);
-        assert_eq!("foo", parsed.options_first("foo1").unwrap().name);
-        assert_eq!("foo", parsed.options_last("foo1").unwrap().name);
-        assert_eq!("foo-", parsed.options_first("foo2").unwrap().name);
-        assert_eq!("foo-longer", parsed.options_last("foo2").unwrap().name);
+        let copy = parsed.clone_options_only();
+        assert_eq!(parsed.options, copy.options);
+        assert_eq!(0, copy.other.len());
+        assert_eq!(0, copy.unknown.len());
+        assert_eq!(1, parsed.other.len());
     }
 
     #[test]
-    fn t_parsed_output_090() {
-        let parsed = OptSpecs::new()
-            .flag(OptFlags::OptionsEverywhere)
+    fn t_create_optspecs_270() {
+        let specs = OptSpecs::new()
             .option("help", "h", OptValue::None)
-            .option("file", "file", OptValue::Required)
-            .getopt(["-h", "foo", "--file=123", "--", "bar", "--file", "456"]);
+            .option("file", "f", OptValue::Required)
+            .remove_option("h")
+            .remove_option("nothing");
 
-        assert_eq!("h", parsed.options_first("help").unwrap().name);
-        assert_eq!("file", parsed.options_first("file").unwrap().name);
+        assert_eq!(1, specs.options.len());
+        assert_eq!(vec!["h"], specs.getopt(["-h"]).unknown);
+
+        let specs = specs.option("help", "h", OptValue::None);
+        assert_eq!(2, specs.options.len());
+        assert_eq!(true, specs.getopt(["-h"]).option_exists("help"));
         assert_eq!(
-            "123",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
+            Err(OptionSpecError::DuplicateName("h".to_string())),
+            specs.try_option("help", "h", OptValue::None)
         );
+    }
 
-        assert_eq!(4, parsed.other.len());
-        assert_eq!("foo", parsed.other[0]);
-        assert_eq!("bar", parsed.other[1]);
-        assert_eq!("--file", parsed.other[2]);
-        assert_eq!("456", parsed.other[3]);
+    #[test]
+    fn t_parsed_output_960() {
+        let specs = OptSpecs::new()
+            .option("help", "help", OptValue::None)
+            .option("verbose", "v", OptValue::None)
+            .flag(OptFlags::IgnoreUnknown);
+
+        let parsed = specs.getopt(["--foo", "-xv", "--help=1", "--help", "bar"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(0, parsed.unknown.len());
+        assert_eq!(vec!["bar"], parsed.other);
+
+        let parsed = specs
+            .clone_empty()
+            .option("verbose", "v", OptValue::None)
+            .limit_options(1)
+            .limit_other_args(0)
+            .limit_unknown_options(0)
+            .getopt(["-v", "-x", "-v"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(0, parsed.unknown.len());
     }
 
     #[test]
-    fn t_parsed_output_100() {
+    fn t_parsed_output_970() {
         let parsed = OptSpecs::new()
             .option("file", "file", OptValue::Required)
-            .getopt(["--file=", "--file"]);
+            .option("verbose", "v", OptValue::None)
+            .flag(OptFlags::ShellStyleComments)
+            .getopt([
+                "#start",
+                "--file",
+                "#foo",
+                "-v",
+                "# comment",
+                "bar",
+                "#x",
+                "--",
+                "#y",
+                "z",
+            ]);
 
-        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
-        assert_eq!(
-            "",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
-        );
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(Some("#foo"), parsed.options[0].value.as_deref());
+        assert_eq!(3, parsed.options[1].source_index);
+        assert_eq!(0, parsed.unknown.len());
+        assert_eq!(vec!["bar", "--", "z"], parsed.other);
     }
 
     #[test]
-    fn t_parsed_output_110() {
+    fn t_parsed_output_980() {
         let parsed = OptSpecs::new()
-            .option("file", "f", OptValue::Required)
-            .option("debug", "d", OptValue::Required)
-            .getopt(["-fx", "-d", "", "-f"]);
+            .option("timeout", "timeout", OptValue::Required)
+            .option("delay", "delay", OptValue::Required)
+            .getopt(["--timeout=1h", "--delay=5x"]);
 
-        assert_eq!(true, parsed.options_first("file").unwrap().value_required);
         assert_eq!(
-            "x",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
+            Some(Ok(core::time::Duration::from_secs(3600))),
+            parsed.option_value_duration("timeout")
         );
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
+        let err = parsed.option_value_duration("delay").unwrap().unwrap_err();
         assert_eq!(
-            "",
-            parsed
-                .options_first("debug")
-                .unwrap()
-                .value
-                .clone()
-                .unwrap()
+            ParseDurationError {
+                value: "5x".to_string(),
+                kind: ValueErrorKind::InvalidUnit,
+            },
+            err
         );
+        assert_eq!("Invalid duration \"5x\" (unknown unit).", err.to_string());
+        assert_eq!(None, parsed.option_value_duration("nothing"));
     }
 
     #[test]
-    fn t_parsed_output_120() {
-        let parsed = OptSpecs::new()
-            .option("file", "f", OptValue::Required)
-            .option("debug", "d", OptValue::Required)
-            .getopt(["-f123", "-d", "", "-f", "456", "-f"]);
-
-        let f: Vec<&String> = parsed.options_value_all("file").collect();
-        assert_eq!(2, f.len());
-        assert_eq!("123", f[0]);
-        assert_eq!("456", f[1]);
+    fn t_parsed_output_990() {
+        let specs = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .option("help", "help", OptValue::None)
+            .flag(OptFlags::StopAtFirstUnknown);
+
+        let parsed = specs.getopt(["-v", "--foo=bar", "-v", "baz"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["foo"], parsed.unknown);
+        assert_eq!(vec!["-v", "baz"], parsed.other);
+
+        let parsed = specs.getopt(["-vxv", "--help"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["x"], parsed.unknown);
+        assert_eq!(vec!["-v", "--help"], parsed.other);
+
+        let parsed = specs.getopt(["-vx", "foo"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["x"], parsed.unknown);
+        assert_eq!(vec!["foo"], parsed.other);
+
+        let parsed = specs
+            .clone()
+            .flag(OptFlags::StopAtUnknown)
+            .getopt(["-vxv", "--help"]);
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(vec!["-vxv", "--help"], parsed.other);
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(1, d.len());
-        assert_eq!("", d[0]);
+        let parsed = specs.getopt(["--help=x", "-v"]);
+        assert_eq!(0, parsed.options.len());
+        assert_eq!(vec!["help="], parsed.unknown);
+        assert_eq!(vec!["-v"], parsed.other);
 
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
-        let m: Vec<&Opt> = parsed.required_value_missing().collect();
-        assert_eq!(1, m.len());
-        assert_eq!("f", m[0].name);
+        let parsed = specs.getopt(["-v", "--help", "--", "-x"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(0, parsed.unknown.len());
+        assert_eq!(vec!["-x"], parsed.other);
     }
 
     #[test]
-    fn t_parsed_output_125() {
+    fn t_parsed_output_1000() {
         let parsed = OptSpecs::new()
-            .option("file", "f", OptValue::Required)
-            .option("debug", "d", OptValue::RequiredNonEmpty)
-            .getopt(["-f123", "-d", "", "-f", "456", "-f"]);
-
-        let f: Vec<&String> = parsed.options_value_all("file").collect();
-        assert_eq!(2, f.len());
-        assert_eq!("123", f[0]);
-        assert_eq!("456", f[1]);
+            .option("max", "max-size", OptValue::Required)
+            .option("buffer", "buffer-size", OptValue::Required)
+            .getopt(["--max-size=1GB", "--buffer-size=4KiB", "--max-size=2KB"]);
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(0, d.len());
+        assert_eq!(Some(Ok(2000)), parsed.option_value_byte_size("max"));
+        assert_eq!(Some(Ok(4096)), parsed.option_value_byte_size("buffer"));
+        assert_eq!(None, parsed.option_value_byte_size("nothing"));
 
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
-        let m: Vec<&Opt> = parsed.required_value_missing().collect();
-        assert_eq!(2, m.len());
-        assert_eq!("d", m[0].name);
-        assert_eq!("f", m[1].name);
+        let parsed = OptSpecs::new()
+            .option("max", "max-size", OptValue::Required)
+            .getopt(["--max-size=1kB"]);
+        let err = parsed.option_value_byte_size("max").unwrap().unwrap_err();
+        assert_eq!(ValueErrorKind::InvalidUnit, err.kind);
+        assert_eq!("Invalid byte size \"1kB\" (unknown unit).", err.to_string());
     }
 
     #[test]
-    fn t_parsed_output_130() {
-        let parsed = OptSpecs::new()
-            .option("file", "file", OptValue::Required)
-            .option("debug", "debug", OptValue::Required)
-            .getopt(["--file=123", "--debug", "", "--file", "456", "--file"]);
-
-        let f: Vec<&String> = parsed.options_value_all("file").collect();
-        assert_eq!(2, f.len());
-        assert_eq!("123", f[0]);
-        assert_eq!("456", f[1]);
+    fn t_parsed_output_1010() {
+        let specs = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .option("lines", "n", OptValue::Required)
+            .flag(OptFlags::AllowNegativeNumbers)
+            .flag(OptFlags::OptionsEverywhere);
+
+        let parsed = specs.getopt(["-n", "-5", "-1", "-v", "-3.14", "-x"]);
+        assert_eq!(Some(&"-5".to_string()), parsed.options_value_last("lines"));
+        assert_eq!(true, parsed.option_exists("verbose"));
+        assert_eq!(vec!["x"], parsed.unknown);
+        assert_eq!(vec!["-1", "-3.14"], parsed.other);
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(1, d.len());
-        assert_eq!("", d[0]);
+        let parsed = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .flag(OptFlags::AllowNegativeNumbers)
+            .getopt(["-v", "-2", "-v"]);
+        assert_eq!(1, parsed.options.len());
+        assert_eq!(vec!["-2", "-v"], parsed.other);
 
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
-        let m: Vec<&Opt> = parsed.required_value_missing().collect();
-        assert_eq!(1, m.len());
-        assert_eq!("file", m[0].name);
+        let parsed = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .getopt(["-1"]);
+        assert_eq!(vec!["1"], parsed.unknown);
+        assert_eq!(0, parsed.other.len());
     }
 
     #[test]
-    fn t_parsed_output_135() {
-        let parsed = OptSpecs::new()
-            .option("file", "file", OptValue::RequiredNonEmpty)
-            .option("debug", "debug", OptValue::RequiredNonEmpty)
-            .getopt(["--file=123", "--debug", "", "--file", "456", "--file="]);
-
-        let f: Vec<&String> = parsed.options_value_all("file").collect();
-        assert_eq!(2, f.len());
-        assert_eq!("123", f[0]);
-        assert_eq!("456", f[1]);
+    fn t_create_optspecs_280() {
+        let mut opts = BTreeMap::new();
+        opts.insert("plugin-a", ("plugin-a", OptValue::Required));
+        opts.insert("plugin-b", ("b", OptValue::None));
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(0, d.len());
+        let parsed = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .with_runtime_options(opts)
+            .getopt(["-hb", "--plugin-a=x"]);
 
-        assert_eq!(None, parsed.options_last("file").unwrap().value);
-        let m: Vec<&Opt> = parsed.required_value_missing().collect();
-        assert_eq!(2, m.len());
-        assert_eq!("debug", m[0].name);
-        assert_eq!("file", m[1].name);
+        assert_eq!(true, parsed.option_exists("help"));
+        assert_eq!(true, parsed.option_exists("plugin-b"));
+        assert_eq!(
+            Some(&"x".to_string()),
+            parsed.options_value_last("plugin-a")
+        );
     }
 
     #[test]
-    fn t_parsed_output_137() {
-        let parsed = OptSpecs::new()
-            .option("debug", "d", OptValue::OptionalNonEmpty)
-            .option("debug", "debug", OptValue::OptionalNonEmpty)
-            .getopt([
-                "-d",
-                "-d123",
-                "--debug",
-                "--debug=",
-                "--debug=456",
-                "--debug=",
-            ]);
+    #[cfg(feature = "std")]
+    fn t_create_optspecs_290() {
+        let mut opts = std::collections::HashMap::new();
+        opts.insert("verbose", ("verbose", OptValue::None));
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(2, d.len());
-        assert_eq!("123", d[0]);
-        assert_eq!("456", d[1]);
-        assert_eq!("123", parsed.options_value_first("debug").unwrap());
-        assert_eq!("456", parsed.options_value_last("debug").unwrap());
+        let specs = OptSpecs::new().with_runtime_options(opts);
+        assert_eq!(true, specs.getopt(["--verbose"]).option_exists("verbose"));
     }
 
     #[test]
-    fn t_parsed_output_140() {
+    fn t_parsed_output_1020() {
         let parsed = OptSpecs::new()
-            .flag(OptFlags::OptionsEverywhere)
+            .option("file", "f", OptValue::Required)
             .option("debug", "d", OptValue::Optional)
-            .option("debug", "debug", OptValue::Optional)
-            .getopt([
-                "-d",
-                "-d123",
-                "-d",
-                "--debug",
-                "--debug=",
-                "foo",
-                "--debug=456",
-                "-d",
-            ]);
+            .option("verbose", "v", OptValue::None)
+            .getopt(["-fa", "-d", "-v", "-fb", "-d1", "-v"]);
+
+        assert_eq!(2, parsed.options_value_count("file"));
+        assert_eq!(1, parsed.options_value_count("debug"));
+        assert_eq!(0, parsed.options_value_count("verbose"));
+        assert_eq!(0, parsed.options_value_count("nothing"));
+
+        let counts = parsed.options_value_count_all();
+        assert_eq!(3, counts.len());
+        assert_eq!(Some(&2), counts.get("file"));
+        assert_eq!(Some(&1), counts.get("debug"));
+        assert_eq!(Some(&0), counts.get("verbose"));
+        assert_eq!(0, Args::new().options_value_count_all().len());
+    }
 
-        let d: Vec<&Opt> = parsed.options_all("debug").collect();
-        assert_eq!(7, d.len());
+    #[test]
+    fn t_parsed_output_1030() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("debug", "d", OptValue::OptionalNonEmpty)
+            .option("verbose", "v", OptValue::None)
+            .flag(OptFlags::AllowShortOptionEquals);
 
-        let d: Vec<&String> = parsed.options_value_all("debug").collect();
-        assert_eq!(3, d.len());
-        assert_eq!("123", d[0]);
-        assert_eq!("", d[1]);
-        assert_eq!("456", d[2]);
-        assert_eq!("123", parsed.options_value_first("debug").unwrap());
-        assert_eq!("456", parsed.options_value_last("debug").unwrap());
+        let parsed = specs.getopt(["-f=hello", "-vd=2", "-d=", "-f", "=x"]);
+        assert_eq!(
+            vec!["hello", "=x"],
+            parsed.options_value_all("file").collect::<Vec<&String>>()
+        );
+        assert_eq!(
+            vec![Some("2".to_string()), None],
+            parsed
+                .options_all("debug")
+                .map(|o| o.value.clone())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(true, parsed.option_exists("verbose"));
 
-        assert_eq!(None, parsed.options_value_first("not-at-all"));
-        assert_eq!(None, parsed.options_value_last("not-at-all"));
+        let parsed = specs.getopt(["-f=", "-f==", "-fx=y", "foo"]);
+        assert_eq!(
+            vec!["", "=", "x=y"],
+            parsed.options_value_all("file").collect::<Vec<&String>>()
+        );
+        assert_eq!(vec!["foo"], parsed.other);
 
-        assert_eq!("foo", parsed.other[0]);
+        let parsed = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .getopt(["-f=hello"]);
+        assert_eq!(
+            Some(&"=hello".to_string()),
+            parsed.options_value_last("file")
+        );
     }
 
     #[test]
-    fn t_parsed_output_150() {
-        let parsed = OptSpecs::new().limit_unknown_options(6).getopt([
-            "-abcd",
-            "-e",
-            "--debug",
-            "-x", // Won't be listed in unknown because of limit.
-            "--",
-            "--debug=",
-            "foo",
-            "--debug=456",
-        ]);
+    fn t_parsed_output_1040() {
+        let specs = OptSpecs::new()
+            .option("verbose", "v", OptValue::None)
+            .option("debug", "d", OptValue::Optional)
+            .option("file", "file", OptValue::Required);
 
-        assert_eq!(0, parsed.options.len());
-        assert_eq!(3, parsed.other.len());
-        assert_eq!(6, parsed.unknown.len());
-        assert_eq!(vec!["a", "b", "c", "d", "e", "debug"], parsed.unknown);
+        let parsed = specs.getopt(["-v", "-d", "--file=a", "-d1"]);
+        let opt = parsed.first_non_flag_option().unwrap();
+        assert_eq!("file", opt.id);
+        assert_eq!(Some("a".to_string()), opt.value);
+
+        let parsed = specs.getopt(["-v", "-d", "foo"]);
+        assert_eq!(None, parsed.first_non_flag_option());
     }
 
     #[test]
-    fn t_parsed_output_160() {
-        let parsed = OptSpecs::new()
+    fn t_parsed_output_1050() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
             .option("file", "file", OptValue::Required)
-            .getopt(["--file", "--", "--", "--"]);
+            .option("verbose", "v", OptValue::None);
 
+        let parsed = specs.getopt_strict(["-v", "--file=a", "foo"]).unwrap();
+        assert_eq!(Some(&"a".to_string()), parsed.options_value_last("file"));
+        assert_eq!(vec!["foo"], parsed.other);
+
+        let errors = specs.getopt_strict(["-vx", "--foo", "--file"]).unwrap_err();
         assert_eq!(
-            "--",
-            parsed.options_first("file").unwrap().value.clone().unwrap()
+            vec![
+                ParseError::UnknownOption {
+                    name: "-x".to_string()
+                },
+                ParseError::UnknownOption {
+                    name: "--foo".to_string()
+                },
+                ParseError::RequiredValueMissing {
+                    option_name: "--file".to_string()
+                },
+            ],
+            errors
+        );
+        assert_eq!("Unknown option '-x'.", errors[0].to_string());
+        assert_eq!("Unknown option '--foo'.", errors[1].to_string());
+        assert_eq!(
+            "Value is required for option '--file'.",
+            errors[2].to_string()
         );
-        assert_eq!(1, parsed.other.len());
-        assert_eq!("--", parsed.other[0]);
-
-        assert_eq!(0, parsed.required_value_missing().count());
     }
 
     #[test]
-    fn t_parsed_output_170() {
-        let parsed = OptSpecs::new().getopt(["foo", "bar"]);
+    fn t_parsed_output_1060() {
+        let specs = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .option("verbose", "v", OptValue::None);
 
-        assert_eq!(None, parsed.options_first("not-at-all"));
-        assert_eq!(None, parsed.options_last("not-at-all"));
+        assert_eq!(0, specs.getopt(["-v", "--file=a"]).validate().len());
+        assert_eq!(0, Args::new().validate().len());
+        assert_eq!(
+            vec![
+                ParseError::UnknownOption {
+                    name: "-x".to_string()
+                },
+                ParseError::RequiredValueMissing {
+                    option_name: "--file".to_string()
+                },
+            ],
+            specs.getopt(["-x", "--file"]).validate()
+        );
+        assert_eq!(0, specs.getopt(["--file", "-x"]).validate().len());
     }
 
     #[test]
-    fn t_parsed_output_180() {
-        let parsed = OptSpecs::new()
-            .limit_unknown_options(3)
-            .option("bar", "bar", OptValue::None)
-            .getopt(["-aaa", "--foo", "--foo", "--bar=", "--bar=", "-x"]);
-
-        assert_eq!(3, parsed.unknown.len());
-        assert_eq!("a", parsed.unknown[0]);
-        assert_eq!("foo", parsed.unknown[1]);
-        assert_eq!("bar=", parsed.unknown[2]);
+    fn t_create_optspecs_300() {
+        let specs = OptSpecs::new()
+            .option_short_only("file", 'f', OptValue::Required)
+            .option_short_only("verbose", 'v', OptValue::None)
+            .option_long_alias('f', "file")
+            .option_long_alias('v', "verbose");
+
+        assert_eq!(4, specs.options.len());
+        assert_eq!("file", specs.options[2].id);
+        assert_eq!("file", specs.options[2].name);
+        assert_eq!(OptValue::Required, specs.options[2].value_type);
+
+        let parsed = specs.getopt(["--file=a", "-fb", "--verbose"]);
+        assert_eq!(2, parsed.options_value_all("file").count());
+        assert_eq!(true, parsed.option_exists("verbose"));
     }
 
     #[test]
-    fn t_parsed_output_190() {
-        let parsed = OptSpecs::new()
-            .option("äiti", "äiti", OptValue::Required)
-            .option("€uro", "€uro", OptValue::Required)
-            .getopt(["--äiti=ööö", "--€uro", "€€€", "--äiti", "ää", "--äiti"]);
-
-        let a: Vec<&String> = parsed.options_value_all("äiti").collect();
-        assert_eq!(2, a.len());
-        assert_eq!("ööö", a[0]);
-        assert_eq!("ää", a[1]);
-        assert_eq!("ööö", parsed.options_value_first("äiti").unwrap());
-        assert_eq!("ää", parsed.options_value_last("äiti").unwrap());
-
-        let e: Vec<&String> = parsed.options_value_all("€uro").collect();
-        assert_eq!(1, e.len());
-        assert_eq!("€€€", e[0]);
-        assert_eq!("€€€", parsed.options_value_first("€uro").unwrap());
-        assert_eq!("€€€", parsed.options_value_last("€uro").unwrap());
-
-        assert_eq!(None, parsed.options_last("äiti").unwrap().value);
+    #[should_panic]
+    fn t_create_optspecs_310() {
+        let _ = OptSpecs::new()
+            .option("file", "file", OptValue::Required)
+            .option_long_alias('f', "file");
+    }
 
-        let m: Vec<&Opt> = parsed.required_value_missing().collect();
-        assert_eq!(1, m.len());
-        assert_eq!("äiti", m[0].name);
-        assert_eq!(None, m[0].value);
+    #[test]
+    #[should_panic]
+    fn t_create_optspecs_320() {
+        let _ = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option_long_alias('f', "x");
     }
 
     #[test]
-    fn t_parsed_output_195() {
-        let parsed = OptSpecs::new().getopt(["-ä€", "--€uro", "äää", "€€€"]);
+    #[cfg(feature = "std")]
+    fn t_parsed_output_1070() {
+        let specs = OptSpecs::new().option("file", "file", OptValue::Required);
 
-        assert_eq!(2, parsed.other.len());
-        assert_eq!("äää", parsed.other[0]);
-        assert_eq!("€€€", parsed.other[1]);
+        let parsed = specs.getopt(["--file=a", "b"]);
+        assert_eq!(
+            false,
+            parsed.print_compact_usage("prog", "[OPTIONS] <file>")
+        );
 
-        assert_eq!(3, parsed.unknown.len());
-        assert_eq!("ä", parsed.unknown[0]);
-        assert_eq!("€", parsed.unknown[1]);
-        assert_eq!("€uro", parsed.unknown[2]);
+        let parsed = specs.getopt(["-x", "--file"]);
+        assert_eq!(true, parsed.print_compact_usage("prog", "[OPTIONS] <file>"));
     }
 
     #[test]
-    fn t_parsed_output_200() {
-        let parsed = OptSpecs::new().limit_other_args(5).getopt(1..10);
-        assert_eq!(5, parsed.other.len());
-        assert_eq!(vec!["1", "2", "3", "4", "5"], parsed.other);
+    fn t_parsed_output_1080() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .option("color", "color", OptValue::Optional);
+
+        let parsed = specs.getopt(["-v", "--color", "-f", "a", "--file=b", "--color=x", "-fc"]);
+        let formatted: Vec<String> = parsed.options.iter().map(|o| o.to_string()).collect();
+        assert_eq!(
+            vec!["-v", "--color", "-fa", "--file=b", "--color=x", "-fc"],
+            formatted
+        );
+        let reparsed = specs.getopt(&formatted);
+        assert_eq!(
+            formatted,
+            reparsed
+                .options
+                .iter()
+                .map(|o| o.to_string())
+                .collect::<Vec<String>>()
+        );
     }
 
     #[test]
-    fn t_parsed_output_210() {
-        let parsed = OptSpecs::new().limit_other_args(0).getopt(1..10);
-        assert_eq!(0, parsed.other.len());
+    fn t_parsed_output_1090() {
+        let specs = OptSpecs::new()
+            .option("begin", "begin", OptValue::None)
+            .option("end", "end", OptValue::None)
+            .option("a", "a", OptValue::None)
+            .option("b", "b", OptValue::Required);
+
+        let parsed = specs.getopt(["-a", "--begin", "-a", "-bx", "--end", "-a", "--end"]);
+        let between = parsed.options_between_ids("begin", "end");
+        assert_eq!(2, between.len());
+        assert_eq!("a", between[0].id);
+        assert_eq!("b", between[1].id);
+        assert_eq!(Some("x"), between[1].value_str());
+
+        assert_eq!(0, parsed.options_between_ids("end", "begin").len());
+        assert_eq!(0, parsed.options_between_ids("begin", "nothing").len());
+        assert_eq!(0, parsed.options_between_ids("begin", "begin").len());
+        assert_eq!(
+            0,
+            specs
+                .getopt(["--begin", "--end"])
+                .options_between_ids("begin", "end")
+                .len()
+        );
     }
 
     #[test]
-    fn t_parsed_output_220() {
-        let parsed = OptSpecs::new()
+    fn t_parsed_output_1100() {
+        let specs = OptSpecs::new()
             .option("file", "f", OptValue::Required)
-            .option("file", "file", OptValue::Required)
-            .option("help", "help", OptValue::None)
-            .limit_options(3)
-            .limit_other_args(1)
-            .limit_unknown_options(3)
-            .getopt([
-                "--unknown",
-                "--help=",
-                "-ab",
-                "-f",
-                "one",
-                "-ftwo",
-                "--file",
-                "three",
-                "--file",
-                "four",
-                "other1",
-                "other2",
-            ]);
+            .option("verbose", "verbose", OptValue::None)
+            .option("color", "color", OptValue::Optional)
+            .flag(OptFlags::OptionsEverywhere);
 
-        assert_eq!(3, parsed.options.len());
-        assert_eq!(
-            vec!["one", "two", "three"],
-            parsed.options_value_all("file").collect::<Vec<&String>>()
-        );
+        let parsed = specs.getopt(["-f", "a", "foo", "-x", "--color=auto", "--", "-v"]);
+        let line = parsed.to_string();
+        assert_eq!("-fa --color=auto -x -- foo -v", line);
 
-        assert_eq!(1, parsed.other.len());
-        assert_eq!("other1", parsed.other[0]);
+        let reparsed = specs.getopt(line.split(' '));
+        assert_eq!(line, reparsed.to_string());
+        assert_eq!(parsed.other, reparsed.other);
+        assert_eq!(parsed.unknown, reparsed.unknown);
 
-        assert_eq!(3, parsed.unknown.len());
-        assert_eq!(vec!["unknown", "help=", "a"], parsed.unknown);
+        assert_eq!("--verbose", specs.getopt(["--verbose"]).to_string());
+        assert_eq!("-- foo", specs.getopt(["foo"]).to_string());
+        assert_eq!("", Args::new().to_string());
     }
 
     #[test]
-    fn t_parsed_output_230() {
-        let parsed = OptSpecs::new()
+    fn t_create_optspecs_330() {
+        let specs = OptSpecs::new()
+            .option("help", "h", OptValue::None)
+            .option("help", "help", OptValue::None)
             .option("file", "f", OptValue::Required)
             .option("file", "file", OptValue::Required)
-            .limit_options(3)
-            .getopt(["-f", "one", "-ftwo", "--file=three", "--unknown"]);
+            .option("verbose", "v", OptValue::Optional)
+            .option("verbose", "verbose", OptValue::Optional);
 
         assert_eq!(
-            vec!["one", "two", "three"],
-            parsed.options_value_all("file").collect::<Vec<&String>>()
+            "[-h|--help] [-f|--file <VALUE>] [-v|--verbose[=VALUE]]",
+            specs.to_string()
         );
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("unknown", parsed.unknown[0]);
+        assert_eq!(specs.summary_string(), alloc::format!("{}", specs));
+        assert_eq!("", OptSpecs::new().to_string());
     }
 
     #[test]
-    fn t_parsed_output_240() {
-        let parsed = OptSpecs::new()
-            .option("help", "h", OptValue::None)
-            .limit_options(3)
-            .getopt(["-xhhhh"]);
+    fn t_parsed_output_1110() {
+        let specs = OptSpecs::new()
+            .option_no_prefix("build", "build", OptValue::None)
+            .option_no_prefix("target", "target", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .option_exclusive(&["build", "verbose"])
+            .option_requires_id("build", "target");
+
+        let parsed = specs.getopt(["build", "-v", "target", "x"]);
+        let line = parsed.to_string();
+        assert_eq!("build -v target x", line);
+        assert_eq!(line, specs.getopt(line.split(' ')).to_string());
+        assert_eq!(true, parsed.options[0].no_prefix);
+        assert_eq!(false, parsed.options[0].is_long());
+        assert_eq!(false, parsed.options[0].is_short());
+        assert_eq!(false, parsed.options[1].no_prefix);
+        assert_eq!("build", parsed.options[0].to_string());
+        assert_eq!("target x", parsed.options[2].to_string());
+
+        let parsed = specs.getopt(["build"]);
+        assert_eq!(
+            "Option \"build\" requires option \"target\".",
+            parsed.check_dependencies(&specs)[0].to_string()
+        );
+        let parsed = specs.getopt(["build", "-v"]);
+        assert_eq!(
+            "build and -v are mutually exclusive; please use only one",
+            parsed
+                .verify_exclusive_groups(&specs)
+                .unwrap_err()
+                .to_string()
+        );
 
-        assert_eq!(3, parsed.options.len());
-        assert_eq!(true, parsed.options_first("help").is_some());
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("x", parsed.unknown[0]);
+        let parsed = specs.getopt(["target"]);
+        assert_eq!(
+            vec![ParseError::RequiredValueMissing {
+                option_name: "target".to_string()
+            }],
+            parsed.validate()
+        );
+        assert_eq!(
+            "Value is required for option 'target'.",
+            parsed.validate()[0].to_string()
+        );
     }
 
     #[test]
-    fn t_parsed_output_250() {
+    #[cfg(feature = "std")]
+    fn t_parsed_output_1120() {
         let parsed = OptSpecs::new()
-            .option("help", "h", OptValue::None)
-            .limit_options(3)
-            .getopt(["-x", "-h", "-h", "-h", "-h"]);
+            .option_no_prefix("target", "target", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .getopt(["target", "x", "-v"]);
 
-        assert_eq!(3, parsed.options.len());
-        assert_eq!(true, parsed.options_first("help").is_some());
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("x", parsed.unknown[0]);
+        let mut out: Vec<u8> = Vec::new();
+        parsed.debug_print_to(&mut out).unwrap();
+        assert_eq!(
+            "1 [O] target (target) = \"x\"\n\
+             2 [O] -v (verbose)\n",
+            String::from_utf8(out).unwrap()
+        );
     }
 
     #[test]
-    fn t_parsed_output_260() {
-        let parsed = OptSpecs::new()
-            .option("help", "h", OptValue::None)
-            .limit_options(3)
-            .getopt(["-x", "-h", "-h", "--", "-h", "-h"]);
+    fn t_parsed_output_1130() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("mode", "m", OptValue::Required)
+            .option("verbose", "v", OptValue::None)
+            .apply_defaults(&[("mode", "fast"), ("file", "a.txt")])
+            .limit_options(2);
 
+        let parsed = specs.getopt(["-v", "-v", "-v"]);
         assert_eq!(2, parsed.options.len());
-        assert_eq!(true, parsed.options_first("help").is_some());
-        assert_eq!(2, parsed.other.len());
-        assert_eq!(vec!["-h", "-h"], parsed.other);
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("x", parsed.unknown[0]);
+        assert_eq!(false, parsed.option_exists("mode"));
+
+        let parsed = specs.getopt(["-v"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!(Some(&"fast".to_string()), parsed.options_value_last("mode"));
+        assert_eq!(false, parsed.option_exists("file"));
     }
 
     #[test]
-    fn t_parsed_output_270() {
-        let parsed = OptSpecs::new()
-            .flag(OptFlags::OptionsEverywhere)
-            .option("help", "h", OptValue::None)
-            .option("file", "f", OptValue::Required)
-            .limit_options(1)
-            .limit_other_args(2)
-            .limit_unknown_options(1)
-            .getopt(["bar", "-habf", "123", "foo"]);
-
-        // "123" must be parsed as "f" option's value even though it is
-        // beyond limit_options.
-        assert_eq!(true, parsed.options_first("help").is_some());
-        assert_eq!(false, parsed.options_first("file").is_some());
-        assert_eq!(2, parsed.other.len());
-        assert_eq!("bar", parsed.other[0]);
-        assert_eq!("foo", parsed.other[1]);
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("a", parsed.unknown[0]);
+    #[cfg(feature = "std")]
+    fn t_parsed_output_1140() {
+        std::env::set_var("JUST_GETOPT_TEST_1140", "x");
+        let specs = OptSpecs::new()
+            .option_parse_env("file", "file", OptValue::Required, "JUST_GETOPT_TEST_1140")
+            .option("verbose", "v", OptValue::None)
+            .limit_options(2);
+
+        let parsed = specs.getopt(["-v", "-v"]);
+        assert_eq!(2, parsed.options.len());
+        assert_eq!("file", parsed.options[0].id);
+        assert_eq!("verbose", parsed.options[1].id);
     }
 
     #[test]
-    fn t_parsed_output_280() {
-        let parsed = OptSpecs::new()
-            .flag(OptFlags::OptionsEverywhere)
-            .option("help", "help", OptValue::None)
-            .option("file", "file", OptValue::Required)
-            .limit_options(1)
-            .limit_other_args(2)
-            .limit_unknown_options(1)
-            .getopt(["bar", "--help", "-ab", "--file", "123", "foo"]);
-
-        // "123" must be parsed as "--file" option's value even though
-        // it is beyond limit_options.
-        assert_eq!(true, parsed.options_first("help").is_some());
-        assert_eq!(false, parsed.options_first("file").is_some());
-        assert_eq!(2, parsed.other.len());
-        assert_eq!("bar", parsed.other[0]);
-        assert_eq!("foo", parsed.other[1]);
-        assert_eq!(1, parsed.unknown.len());
-        assert_eq!("a", parsed.unknown[0]);
+    fn t_create_optspecs_340() {
+        let specs = OptSpecs::new()
+            .option_range("level", "l", 1, 5)
+            .option_long_alias('l', "level")
+            .option_in_category(&["output"], "file", "f", OptValue::Required)
+            .option_long_alias('f', "file");
+
+        assert_eq!(Some((1, 5)), specs.options[1].range);
+        assert_eq!(vec!["output"], specs.options[3].category);
+
+        let parsed = specs.getopt(["--level=99", "-l99"]);
+        assert_eq!(2, parsed.range_violations(&specs).len());
     }
 
     #[test]
-    fn t_parsed_output_290() {
-        let parsed = OptSpecs::new()
-            .option("file", "f", OptValue::RequiredNonEmpty)
-            .option("debug", "d", OptValue::RequiredNonEmpty)
-            .getopt(["-f1", "-d", "", "-f", "", "-f", "2", "-f"]);
-
-        let mut i = parsed.options_all("file").rev();
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!(None, i.next());
-
-        let mut i = parsed.options_all("debug").rev();
-        assert_eq!("d", i.next().unwrap().name);
-        assert_eq!(None, i.next());
-
-        let mut i = parsed.options_value_all("file").rev();
-        assert_eq!("2", i.next().unwrap());
-        assert_eq!("1", i.next().unwrap());
-        assert_eq!(None, i.next());
+    fn t_parsed_output_1150() {
+        let specs = OptSpecs::new()
+            .option("file", "f", OptValue::Required)
+            .option("file", "file", OptValue::Required)
+            .option("color", "c", OptValue::Optional)
+            .option("verbose", "v", OptValue::None)
+            .option_no_prefix("build", "build", OptValue::None)
+            .option_no_prefix("target", "target", OptValue::Required);
+
+        let check = |args: &[&str], expected: &str| {
+            let parsed = specs.getopt(args);
+            let line = parsed.to_string();
+            assert_eq!(expected, line);
+
+            let again = specs.getopt(line.split(' '));
+            assert_eq!(line, again.to_string());
+            let values = |p: &Args| {
+                p.options
+                    .iter()
+                    .map(|o| (o.id.clone(), o.name.clone(), o.value.clone()))
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(values(&parsed), values(&again));
+            assert_eq!(parsed.unknown, again.unknown);
+            assert_eq!(parsed.other, again.other);
+        };
 
-        let mut i = parsed.required_value_missing().rev();
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!("f", i.next().unwrap().name);
-        assert_eq!("d", i.next().unwrap().name);
-        assert_eq!(None, i.next());
+        check(&["-f", "", "foo"], "-f  -- foo");
+        check(&["-f", ""], "-f ");
+        check(&["-vf", "", "-v"], "-v -f  -v");
+        check(&["--file", "", "-ca"], "--file= -ca");
+        check(&["-c", "--file", "x", "-x"], "-c --file=x -x");
+        check(&["build", "target", "", "-v"], "build target  -v");
+        check(&["target", "-v", "--", "-v"], "target -v -- -v");
+        check(&["--foo", "-v", "a", "b"], "-v --foo -- a b");
     }
 }
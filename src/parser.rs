@@ -4,32 +4,40 @@ use alloc::{
     string::{String, ToString},
 };
 
-pub fn parse<I>(specs: &OptSpecs, mut iter: I) -> Args
+pub fn parse<I>(specs: &OptSpecs, iter: I) -> Args
 where
     I: Iterator<Item = String>,
 {
+    let mut iter = iter.enumerate();
     let mut parsed = Args::new();
     let mut option_count: u32 = 0;
     let mut other_count: u32 = 0;
     let mut unknown_count: u32 = 0;
 
-    loop {
+    'parsing: loop {
+        // Parsing can't stop when only the option limit is reached.
+        // Later options and their values must still be recognized so
+        // that they are not collected as other arguments.
         if option_count >= specs.option_limit
             && other_count >= specs.other_limit
-            && unknown_count >= specs.unknown_limit
+            && (unknown_count >= specs.unknown_limit || specs.is_flag(OptFlags::IgnoreUnknown))
         {
             break;
         }
 
-        let arg = match iter.next() {
+        let (index, arg) = match iter.next() {
             None => break,
-            Some(s) => s,
+            Some(i) => i,
         };
 
+        if specs.is_flag(OptFlags::ShellStyleComments) && is_comment(&arg) {
+            continue;
+        }
+
         if is_option_terminator(&arg) {
             break;
-        } else if is_long_option_prefix(&arg) {
-            let name = get_long_option_name(&arg);
+        } else if !specs.is_flag(OptFlags::DisallowLongOptions) && is_long_option_prefix(&arg) {
+            let name = get_long_option_name(&arg, specs.long_value_separator);
 
             if is_valid_long_option_name(&name) {
                 let opt_match = if specs.is_flag(OptFlags::PrefixMatchLongOptions) {
@@ -45,17 +53,23 @@ where
                     match spec.value_type {
                         OptValue::Required | OptValue::RequiredNonEmpty => {
                             value_required = true;
-                            value = if is_long_option_equal_sign(&arg) {
-                                Some(get_long_option_equal_value(&arg))
+                            value = if is_long_option_equal_sign(&arg, specs.long_value_separator) {
+                                Some(get_long_option_equal_value(
+                                    &arg,
+                                    specs.long_value_separator,
+                                ))
                             } else {
-                                iter.next()
+                                iter.next().map(|(_, s)| s)
                             };
                         }
 
                         OptValue::Optional | OptValue::OptionalNonEmpty => {
                             value_required = false;
-                            value = if is_long_option_equal_sign(&arg) {
-                                Some(get_long_option_equal_value(&arg))
+                            value = if is_long_option_equal_sign(&arg, specs.long_value_separator) {
+                                Some(get_long_option_equal_value(
+                                    &arg,
+                                    specs.long_value_separator,
+                                ))
                             } else {
                                 None
                             };
@@ -64,13 +78,18 @@ where
                         OptValue::None => {
                             value_required = false;
                             value = None;
-                            if is_long_option_equal_sign(&arg) {
-                                let n = format!("{}=", name);
-                                if unknown_count < specs.unknown_limit
-                                    && !parsed.unknown.contains(&n)
-                                {
-                                    parsed.unknown.push(n);
-                                    unknown_count += 1;
+                            if is_long_option_equal_sign(&arg, specs.long_value_separator) {
+                                let n = format!("{}{}", name, specs.long_value_separator);
+                                add_unknown(specs, &mut parsed, &mut unknown_count, n);
+                                if specs.is_flag(OptFlags::StopAtUnknown) {
+                                    if other_count < specs.other_limit {
+                                        parsed.other.push(arg);
+                                        other_count += 1;
+                                    }
+                                    break 'parsing;
+                                }
+                                if specs.is_flag(OptFlags::StopAtFirstUnknown) {
+                                    break 'parsing;
                                 }
                                 continue;
                             }
@@ -90,6 +109,8 @@ where
                             name,
                             value_required,
                             value,
+                            source_index: index,
+                            no_prefix: false,
                         });
                         option_count += 1;
                     }
@@ -97,12 +118,22 @@ where
                 }
             }
 
-            if unknown_count < specs.unknown_limit && !parsed.unknown.contains(&name) {
-                parsed.unknown.push(name);
-                unknown_count += 1;
+            add_unknown(specs, &mut parsed, &mut unknown_count, name);
+            if specs.is_flag(OptFlags::StopAtUnknown) {
+                if other_count < specs.other_limit {
+                    parsed.other.push(arg);
+                    other_count += 1;
+                }
+                break;
+            }
+            if specs.is_flag(OptFlags::StopAtFirstUnknown) {
+                break;
             }
             continue;
-        } else if is_short_option_prefix(&arg) {
+        } else if !specs.is_flag(OptFlags::DisallowShortOptions)
+            && is_short_option_prefix(&arg)
+            && !(specs.is_flag(OptFlags::AllowNegativeNumbers) && is_negative_number(&arg))
+        {
             let series = get_short_option_series(&arg);
             let mut char_iter = series.chars();
 
@@ -120,24 +151,18 @@ where
                         match spec.value_type {
                             OptValue::Required | OptValue::RequiredNonEmpty => {
                                 value_required = true;
-                                let mut chars = String::with_capacity(5);
-                                for c in char_iter.by_ref() {
-                                    chars.push(c);
-                                }
+                                let (chars, equals) = get_short_option_value(specs, &mut char_iter);
                                 value = match chars.chars().count() {
-                                    0 => iter.next(),
+                                    0 if !equals => iter.next().map(|(_, s)| s),
                                     _ => Some(chars),
                                 };
                             }
 
                             OptValue::Optional | OptValue::OptionalNonEmpty => {
                                 value_required = false;
-                                let mut chars = String::with_capacity(5);
-                                for c in char_iter.by_ref() {
-                                    chars.push(c);
-                                }
+                                let (chars, equals) = get_short_option_value(specs, &mut char_iter);
                                 value = match chars.chars().count() {
-                                    0 => None,
+                                    0 if !equals => None,
                                     _ => Some(chars),
                                 };
                             }
@@ -161,6 +186,8 @@ where
                                 name,
                                 value_required,
                                 value,
+                                source_index: index,
+                                no_prefix: false,
                             });
                             option_count += 1;
                         }
@@ -168,12 +195,56 @@ where
                     }
                 }
 
-                if unknown_count < specs.unknown_limit && !parsed.unknown.contains(&name) {
-                    parsed.unknown.push(name);
-                    unknown_count += 1;
+                add_unknown(specs, &mut parsed, &mut unknown_count, name);
+                if specs.is_flag(OptFlags::StopAtUnknown) {
+                    if other_count < specs.other_limit {
+                        // The whole argument is forwarded, so the
+                        // options before the unknown one are not
+                        // collected.
+                        parsed.options.retain(|o| o.source_index != index);
+                        parsed.other.push(arg);
+                        other_count += 1;
+                    }
+                    break 'parsing;
+                }
+                if specs.is_flag(OptFlags::StopAtFirstUnknown) {
+                    let rest: String = char_iter.collect();
+                    if !rest.is_empty() && other_count < specs.other_limit {
+                        parsed
+                            .other
+                            .push(format!("{}{}", SHORT_OPTION_PREFIX, rest));
+                        other_count += 1;
+                    }
+                    break 'parsing;
                 }
                 continue;
             }
+        } else if let Some(spec) = specs.get_no_prefix_match(&arg) {
+            let value_required = matches!(
+                spec.value_type,
+                OptValue::Required | OptValue::RequiredNonEmpty
+            );
+            let mut value = if value_required {
+                iter.next().map(|(_, s)| s)
+            } else {
+                None
+            };
+
+            if option_count < specs.option_limit {
+                if spec.value_type == OptValue::RequiredNonEmpty {
+                    value = value.filter(|v| !v.is_empty());
+                }
+
+                parsed.options.push(Opt {
+                    id: spec.id.clone(),
+                    name: arg,
+                    value_required,
+                    value,
+                    source_index: index,
+                    no_prefix: true,
+                });
+                option_count += 1;
+            }
         } else {
             if other_count < specs.other_limit {
                 parsed.other.push(arg);
@@ -192,7 +263,8 @@ where
 
         match iter.next() {
             None => break,
-            Some(s) => {
+            Some((_, s)) if specs.is_flag(OptFlags::ShellStyleComments) && is_comment(&s) => (),
+            Some((_, s)) => {
                 if other_count < specs.other_limit {
                     parsed.other.push(s);
                     other_count += 1;
@@ -204,7 +276,29 @@ where
     parsed
 }
 
+fn add_unknown(specs: &OptSpecs, parsed: &mut Args, unknown_count: &mut u32, name: String) {
+    if specs.is_flag(OptFlags::IgnoreUnknown) {
+        return;
+    }
+    if *unknown_count < specs.unknown_limit && !parsed.unknown.contains(&name) {
+        parsed.unknown.push(name);
+        *unknown_count += 1;
+    }
+}
+
+fn get_short_option_value(specs: &OptSpecs, chars: &mut core::str::Chars) -> (String, bool) {
+    let mut value: String = chars.collect();
+    let mut equals = false;
+    if specs.is_flag(OptFlags::AllowShortOptionEquals) && value.starts_with(SHORT_OPTION_EQUALS) {
+        value.remove(0);
+        equals = true;
+    }
+    (value, equals)
+}
+
 const OPTION_TERMINATOR: &str = "--";
+const COMMENT_PREFIX: char = '#';
+const SHORT_OPTION_EQUALS: char = '=';
 const LONG_OPTION_PREFIX: &str = "--";
 const LONG_OPTION_PREFIX_COUNT: usize = 2;
 const LONG_OPTION_NAME_MIN_COUNT: usize = 2;
@@ -213,6 +307,10 @@ const SHORT_OPTION_PREFIX_COUNT: usize = 1;
 const INVALID_SHORT_OPTION_CHARS: &str = " -";
 const INVALID_LONG_OPTION_CHARS: &str = " =";
 
+fn is_comment(s: &str) -> bool {
+    s.starts_with(COMMENT_PREFIX)
+}
+
 fn is_option_terminator(s: &str) -> bool {
     s == OPTION_TERMINATOR
 }
@@ -231,20 +329,20 @@ fn get_long_option(s: &str) -> String {
     s.chars().skip(LONG_OPTION_PREFIX_COUNT).collect()
 }
 
-fn get_long_option_name(s: &str) -> String {
-    get_long_option(s).split('=').next().unwrap().to_string()
+fn get_long_option_name(s: &str, sep: char) -> String {
+    get_long_option(s).split(sep).next().unwrap().to_string()
 }
 
-fn is_long_option_equal_sign(s: &str) -> bool {
+fn is_long_option_equal_sign(s: &str, sep: char) -> bool {
     get_long_option(s)
         .chars()
         .skip(LONG_OPTION_NAME_MIN_COUNT)
-        .any(|c| c == '=')
+        .any(|c| c == sep)
 }
 
-fn get_long_option_equal_value(s: &str) -> String {
+fn get_long_option_equal_value(s: &str, sep: char) -> String {
     get_long_option(s)
-        .split_once('=')
+        .split_once(sep)
         .map_or_else(|| "", |(_, v)| v)
         .to_string()
 }
@@ -259,6 +357,10 @@ pub fn is_valid_short_option_name(s: &str) -> bool {
     s.chars().count() == 1 && !INVALID_SHORT_OPTION_CHARS.contains(s)
 }
 
+pub fn is_valid_no_prefix_option_name(s: &str) -> bool {
+    !s.is_empty() && !s.starts_with('-') && !s.contains(' ')
+}
+
 fn is_short_option_prefix(s: &str) -> bool {
     s.starts_with(SHORT_OPTION_PREFIX)
         && s.chars()
@@ -268,11 +370,19 @@ fn is_short_option_prefix(s: &str) -> bool {
     // .is_some_and(|c| !INVALID_SHORT_OPTION_CHARS.contains(c))
 }
 
+fn is_negative_number(s: &str) -> bool {
+    s.starts_with(SHORT_OPTION_PREFIX)
+        && s.chars()
+            .nth(SHORT_OPTION_PREFIX_COUNT)
+            .map_or(false, |c| c.is_ascii_digit())
+}
+
 fn get_short_option_series(s: &str) -> String {
     s.chars().skip(SHORT_OPTION_PREFIX_COUNT).collect()
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -310,43 +420,51 @@ mod tests {
 
     #[test]
     fn t_get_long_option_name() {
-        assert_eq!("abc", get_long_option_name("--abc"));
-        assert_eq!("ä€", get_long_option_name("--ä€"));
-        assert_eq!("abc", get_long_option_name("--abc="));
-        assert_eq!("abc", get_long_option_name("--abc=1"));
-        assert_eq!("abc", get_long_option_name("--abc=134"));
-        assert_eq!("abc", get_long_option_name("--abc=134="));
-        assert_eq!("abc", get_long_option_name("--abc=134=123"));
-        assert_eq!("abc-def", get_long_option_name("--abc-def=  "));
-        assert_eq!("abc-ä€", get_long_option_name("--abc-ä€=  "));
+        assert_eq!("abc", get_long_option_name("--abc", '='));
+        assert_eq!("ä€", get_long_option_name("--ä€", '='));
+        assert_eq!("abc", get_long_option_name("--abc=", '='));
+        assert_eq!("abc", get_long_option_name("--abc=1", '='));
+        assert_eq!("abc", get_long_option_name("--abc=134", '='));
+        assert_eq!("abc", get_long_option_name("--abc=134=", '='));
+        assert_eq!("abc", get_long_option_name("--abc=134=123", '='));
+        assert_eq!("abc-def", get_long_option_name("--abc-def=  ", '='));
+        assert_eq!("abc-ä€", get_long_option_name("--abc-ä€=  ", '='));
+        assert_eq!("abc", get_long_option_name("--abc:1=2", ':'));
+        assert_eq!("abc=1", get_long_option_name("--abc=1", ':'));
     }
 
     #[test]
     fn t_is_long_option_equal_sign() {
-        assert_eq!(true, is_long_option_equal_sign("--abc="));
-        assert_eq!(true, is_long_option_equal_sign("--ab="));
-        assert_eq!(true, is_long_option_equal_sign("--ab=1"));
-        assert_eq!(true, is_long_option_equal_sign("--ab=123"));
-        assert_eq!(true, is_long_option_equal_sign("--ä€=123"));
-        assert_eq!(true, is_long_option_equal_sign("--ab=123=123"));
-        assert_eq!(false, is_long_option_equal_sign("--abcd"));
-        assert_eq!(false, is_long_option_equal_sign("--ab"));
-        assert_eq!(false, is_long_option_equal_sign("--a="));
+        assert_eq!(true, is_long_option_equal_sign("--abc=", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ab=", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ab=1", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ab=123", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ä€=123", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ab=123=123", '='));
+        assert_eq!(false, is_long_option_equal_sign("--abcd", '='));
+        assert_eq!(false, is_long_option_equal_sign("--ab", '='));
+        assert_eq!(false, is_long_option_equal_sign("--a=", '='));
+        assert_eq!(true, is_long_option_equal_sign("--ab:1", ':'));
+        assert_eq!(false, is_long_option_equal_sign("--ab=1", ':'));
     }
 
     #[test]
     fn t_get_long_option_equal_value() {
-        assert_eq!("", get_long_option_equal_value("--abc"));
-        assert_eq!("", get_long_option_equal_value("--abc="));
-        assert_eq!("1", get_long_option_equal_value("--abc=1"));
-        assert_eq!("=", get_long_option_equal_value("--abc=="));
-        assert_eq!("--", get_long_option_equal_value("--abc=--"));
-        assert_eq!("123", get_long_option_equal_value("--abc=123"));
-        assert_eq!(" 12 3 ", get_long_option_equal_value("--abc= 12 3 "));
-        assert_eq!("123=123=", get_long_option_equal_value("--abc=123=123="));
-        assert_eq!("!", get_long_option_equal_value("--abc-def=!"));
-        assert_eq!("!", get_long_option_equal_value("--abc-ä€=!"));
-        assert_eq!("öOö", get_long_option_equal_value("--abc-ä€=öOö"));
+        assert_eq!("", get_long_option_equal_value("--abc", '='));
+        assert_eq!("", get_long_option_equal_value("--abc=", '='));
+        assert_eq!("1", get_long_option_equal_value("--abc=1", '='));
+        assert_eq!("=", get_long_option_equal_value("--abc==", '='));
+        assert_eq!("--", get_long_option_equal_value("--abc=--", '='));
+        assert_eq!("123", get_long_option_equal_value("--abc=123", '='));
+        assert_eq!(" 12 3 ", get_long_option_equal_value("--abc= 12 3 ", '='));
+        assert_eq!(
+            "123=123=",
+            get_long_option_equal_value("--abc=123=123=", '=')
+        );
+        assert_eq!("!", get_long_option_equal_value("--abc-def=!", '='));
+        assert_eq!("!", get_long_option_equal_value("--abc-ä€=!", '='));
+        assert_eq!("öOö", get_long_option_equal_value("--abc-ä€=öOö", '='));
+        assert_eq!("1=2:3", get_long_option_equal_value("--abc:1=2:3", ':'));
     }
 
     #[test]
@@ -377,6 +495,16 @@ mod tests {
         assert_eq!(false, is_valid_short_option_name(" "));
     }
 
+    #[test]
+    fn t_is_valid_no_prefix_option_name() {
+        assert_eq!(true, is_valid_no_prefix_option_name("build"));
+        assert_eq!(true, is_valid_no_prefix_option_name("b"));
+        assert_eq!(true, is_valid_no_prefix_option_name("a=b"));
+        assert_eq!(false, is_valid_no_prefix_option_name(""));
+        assert_eq!(false, is_valid_no_prefix_option_name("-b"));
+        assert_eq!(false, is_valid_no_prefix_option_name("a b"));
+    }
+
     #[test]
     fn t_is_short_option_prefix() {
         assert_eq!(true, is_short_option_prefix("-a"));
@@ -397,6 +525,20 @@ mod tests {
         assert_eq!(false, is_short_option_prefix("--a"));
     }
 
+    #[test]
+    fn t_is_negative_number() {
+        assert_eq!(true, is_negative_number("-1"));
+        assert_eq!(true, is_negative_number("-15"));
+        assert_eq!(true, is_negative_number("-3.14"));
+        assert_eq!(true, is_negative_number("-0x"));
+        assert_eq!(false, is_negative_number("-"));
+        assert_eq!(false, is_negative_number("-a1"));
+        assert_eq!(false, is_negative_number("--1"));
+        assert_eq!(false, is_negative_number("-.5"));
+        assert_eq!(false, is_negative_number("1"));
+        assert_eq!(false, is_negative_number(""));
+    }
+
     #[test]
     fn t_get_short_option_series() {
         assert_eq!("a", get_short_option_series("-a"));
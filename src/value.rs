@@ -0,0 +1,186 @@
+use alloc::string::{String, ToString};
+use core::{fmt, time::Duration};
+
+/// The reason why parsing a value with a unit failed.
+///
+/// This is used in [`ParseDurationError`] and [`ParseByteSizeError`]
+/// structs.
+
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum ValueErrorKind {
+    /// The value is empty.
+    Empty,
+    /// The value does not start with a non-negative integer.
+    InvalidNumber,
+    /// The unit suffix is not known.
+    InvalidUnit,
+    /// The result does not fit in the value type.
+    Overflow,
+}
+
+impl fmt::Display for ValueErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Empty => "no number",
+            Self::InvalidNumber => "invalid number",
+            Self::InvalidUnit => "unknown unit",
+            Self::Overflow => "out of range",
+        })
+    }
+}
+
+/// Error from parsing a duration value.
+///
+/// This is returned by [`Args::option_value_duration`](crate::Args::option_value_duration)
+/// method when option's value is not a valid duration.
+
+#[derive(Debug, PartialEq)]
+pub struct ParseDurationError {
+    /// The value which could not be parsed.
+    pub value: String,
+    /// The reason why parsing failed.
+    pub kind: ValueErrorKind,
+}
+
+impl fmt::Display for ParseDurationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid duration \"{}\" ({}).", self.value, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseDurationError {}
+
+/// Error from parsing a byte size value.
+///
+/// This is returned by [`Args::option_value_byte_size`](crate::Args::option_value_byte_size)
+/// method when option's value is not a valid byte size.
+
+#[derive(Debug, PartialEq)]
+pub struct ParseByteSizeError {
+    /// The value which could not be parsed.
+    pub value: String,
+    /// The reason why parsing failed.
+    pub kind: ValueErrorKind,
+}
+
+impl fmt::Display for ParseByteSizeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Invalid byte size \"{}\" ({}).", self.value, self.kind)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ParseByteSizeError {}
+
+pub fn parse_duration(s: &str) -> Result<Duration, ParseDurationError> {
+    let seconds = parse_with_unit(s, |unit| match unit {
+        "" | "s" => Some(1),
+        "m" => Some(60),
+        "h" => Some(60 * 60),
+        "d" => Some(24 * 60 * 60),
+        _ => None,
+    })
+    .map_err(|kind| ParseDurationError {
+        value: s.to_string(),
+        kind,
+    })?;
+    Ok(Duration::from_secs(seconds))
+}
+
+pub fn parse_byte_size(s: &str) -> Result<u64, ParseByteSizeError> {
+    parse_with_unit(s, |unit| match unit {
+        "" | "B" => Some(1),
+        "KB" => Some(1000),
+        "MB" => Some(1000_u64.pow(2)),
+        "GB" => Some(1000_u64.pow(3)),
+        "TB" => Some(1000_u64.pow(4)),
+        "PB" => Some(1000_u64.pow(5)),
+        "EB" => Some(1000_u64.pow(6)),
+        "KiB" => Some(1 << 10),
+        "MiB" => Some(1 << 20),
+        "GiB" => Some(1 << 30),
+        "TiB" => Some(1 << 40),
+        "PiB" => Some(1 << 50),
+        "EiB" => Some(1 << 60),
+        _ => None,
+    })
+    .map_err(|kind| ParseByteSizeError {
+        value: s.to_string(),
+        kind,
+    })
+}
+
+/// Parse a non-negative integer followed by a unit suffix. The
+/// `multiplier` function returns the multiplier for a known unit.
+fn parse_with_unit<F>(s: &str, multiplier: F) -> Result<u64, ValueErrorKind>
+where
+    F: Fn(&str) -> Option<u64>,
+{
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (number, unit) = s.split_at(split);
+
+    if number.is_empty() {
+        return Err(if unit.is_empty() {
+            ValueErrorKind::Empty
+        } else {
+            ValueErrorKind::InvalidNumber
+        });
+    }
+
+    let multiplier = multiplier(unit).ok_or(ValueErrorKind::InvalidUnit)?;
+    number
+        .parse::<u64>()
+        .map_err(|_| ValueErrorKind::Overflow)?
+        .checked_mul(multiplier)
+        .ok_or(ValueErrorKind::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn t_parse_duration() {
+        assert_eq!(Ok(Duration::from_secs(10)), parse_duration("10"));
+        assert_eq!(Ok(Duration::from_secs(10)), parse_duration("10s"));
+        assert_eq!(Ok(Duration::from_secs(300)), parse_duration("5m"));
+        assert_eq!(Ok(Duration::from_secs(3600)), parse_duration("1h"));
+        assert_eq!(Ok(Duration::from_secs(172_800)), parse_duration("2d"));
+        assert_eq!(Ok(Duration::from_secs(0)), parse_duration("0d"));
+
+        let kind = |s| parse_duration(s).unwrap_err().kind;
+        assert_eq!(ValueErrorKind::Empty, kind(""));
+        assert_eq!(ValueErrorKind::InvalidNumber, kind("s"));
+        assert_eq!(ValueErrorKind::InvalidNumber, kind("-1s"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("10x"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("1.5h"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("10 s"));
+        assert_eq!(ValueErrorKind::Overflow, kind("18446744073709551615d"));
+        assert_eq!(ValueErrorKind::Overflow, kind("99999999999999999999"));
+    }
+
+    #[test]
+    fn t_parse_byte_size() {
+        assert_eq!(Ok(512), parse_byte_size("512"));
+        assert_eq!(Ok(512), parse_byte_size("512B"));
+        assert_eq!(Ok(2000), parse_byte_size("2KB"));
+        assert_eq!(Ok(512_000_000), parse_byte_size("512MB"));
+        assert_eq!(Ok(1_000_000_000), parse_byte_size("1GB"));
+        assert_eq!(Ok(1024), parse_byte_size("1KiB"));
+        assert_eq!(Ok(3 * 1024 * 1024), parse_byte_size("3MiB"));
+        assert_eq!(Ok(1 << 30), parse_byte_size("1GiB"));
+        assert_eq!(Ok(15 << 60), parse_byte_size("15EiB"));
+
+        let kind = |s| parse_byte_size(s).unwrap_err().kind;
+        assert_eq!(ValueErrorKind::Empty, kind(""));
+        assert_eq!(ValueErrorKind::InvalidNumber, kind("KB"));
+        assert_eq!(ValueErrorKind::InvalidNumber, kind("-1KB"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("1kb"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("1.5GB"));
+        assert_eq!(ValueErrorKind::InvalidUnit, kind("1 GB"));
+        assert_eq!(ValueErrorKind::Overflow, kind("16EiB"));
+        assert_eq!(ValueErrorKind::Overflow, kind("99999999999999999999"));
+    }
+}